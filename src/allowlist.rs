@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use crate::errors::HypeBondError;
+
+// Proof that a wallet is on a curve's allowlist with a token allocation.
+// Leaves are keccak(wallet || allocation as u64 LE) and internal nodes hash
+// each sorted pair, so proofs carry no left/right flags.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AllowlistProof {
+    pub allocation: u64,        // Tokens this wallet may buy during the window
+    pub proof: Vec<[u8; 32]>,
+}
+
+impl AllowlistProof {
+    pub fn verify(&self, root: &[u8; 32], wallet: &Pubkey) -> Result<()> {
+        let leaf = keccak::hashv(&[wallet.as_ref(), &self.allocation.to_le_bytes()]).0;
+
+        let computed = self.proof.iter().fold(leaf, |node, sibling| {
+            if node <= *sibling {
+                keccak::hashv(&[&node, sibling]).0
+            } else {
+                keccak::hashv(&[sibling, &node]).0
+            }
+        });
+
+        require!(computed == *root, HypeBondError::NotAllowlisted);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf(wallet: &Pubkey, allocation: u64) -> [u8; 32] {
+        keccak::hashv(&[wallet.as_ref(), &allocation.to_le_bytes()]).0
+    }
+
+    fn parent(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        if a <= b { keccak::hashv(&[&a, &b]).0 } else { keccak::hashv(&[&b, &a]).0 }
+    }
+
+    #[test]
+    fn verifies_sorted_pair_proofs() {
+        let wallets = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let leaves = [leaf(&wallets[0], 100), leaf(&wallets[1], 200), leaf(&wallets[2], 300)];
+        let left = parent(leaves[0], leaves[1]);
+        let root = parent(left, leaves[2]);
+
+        let proof = AllowlistProof { allocation: 200, proof: vec![leaves[0], leaves[2]] };
+        assert!(proof.verify(&root, &wallets[1]).is_ok());
+
+        let proof = AllowlistProof { allocation: 300, proof: vec![left] };
+        assert!(proof.verify(&root, &wallets[2]).is_ok());
+    }
+
+    #[test]
+    fn rejects_wrong_wallet_or_allocation() {
+        let wallets = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let leaves = [leaf(&wallets[0], 100), leaf(&wallets[1], 200)];
+        let root = parent(leaves[0], leaves[1]);
+
+        let inflated = AllowlistProof { allocation: 201, proof: vec![leaves[0]] };
+        assert!(inflated.verify(&root, &wallets[1]).is_err());
+
+        let stolen = AllowlistProof { allocation: 200, proof: vec![leaves[0]] };
+        assert!(stolen.verify(&root, &Pubkey::new_unique()).is_err());
+    }
+}
@@ -0,0 +1,214 @@
+use anchor_lang::prelude::*;
+use crate::constants::{BID_PRICE_LEVELS, PRICE_PRECISION};
+use crate::curve::BondingCurve;
+use crate::errors::HypeBondError;
+use crate::math::{self, Rounding, mul_div_u128};
+
+// Aggregated bids for a curve running a uniform-price batch auction.
+// Bids are bucketed into fixed price levels spanning the discovery range so
+// settlement can clear the whole book without touching individual bids.
+#[account]
+#[derive(Default)]
+pub struct BidBook {
+    pub bonding_curve: Pubkey,
+    pub level_sol: [u64; BID_PRICE_LEVELS],  // Lamports bid at each price level
+    pub total_bid_sol: u64,
+
+    // Settlement results
+    pub settled: bool,
+    pub clearing_level: u8,       // Bids at or above this level are filled
+    pub clearing_price: u64,
+    pub filled_sol: u64,          // Lamports bid at or above the clearing level
+    pub filled_tokens: u64,       // Tokens sold to those bids, shared pro rata by lamports bid
+    pub filled_cost: u64,         // Lamports charged for filled_tokens, credited to the curve
+    pub reserved_tokens: u64,     // Tokens set aside for unclaimed fills
+}
+
+// A single bidder's deposit at one price level
+#[account]
+#[derive(Default)]
+pub struct Bid {
+    pub bonding_curve: Pubkey,
+    pub bidder: Pubkey,
+    pub level: u8,
+    pub sol_amount: u64,
+}
+
+fn tokens_for_sol(sol_amount: u64, price: u64) -> Result<u128> {
+    mul_div_u128(sol_amount as u128, PRICE_PRECISION as u128, price as u128, Rounding::Down)
+}
+
+impl BidBook {
+    // Price of a bid level, evenly spaced from the end price up to the start price
+    pub fn level_price(curve: &BondingCurve, level: u8) -> Result<u64> {
+        let range = curve.discovery_start_price.saturating_sub(curve.discovery_end_price);
+        let step = math::mul_div(range, level as u64, BID_PRICE_LEVELS as u64 - 1, Rounding::Down)?;
+        curve.discovery_end_price
+            .checked_add(step)
+            .ok_or(HypeBondError::MathOverflow.into())
+    }
+
+    pub fn add_bid(&mut self, level: u8, sol_amount: u64) -> Result<()> {
+        require!((level as usize) < BID_PRICE_LEVELS, HypeBondError::InvalidBidLevel);
+
+        let slot = &mut self.level_sol[level as usize];
+        *slot = slot
+            .checked_add(sol_amount)
+            .ok_or(HypeBondError::MathOverflow)?;
+        self.total_bid_sol = self.total_bid_sol
+            .checked_add(sol_amount)
+            .ok_or(HypeBondError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    // Find the highest level whose cumulative demand covers the supply and
+    // clear everyone at or above it at that single price, pro rata. When the
+    // book is undersubscribed every bid fills in full at the lowest level.
+    // Fills are shared by lamports bid, so the ratio stays within u64 maths
+    // however low the clearing price is.
+    pub fn settle(&mut self, curve: &BondingCurve, supply: u64) -> Result<()> {
+        require!(!self.settled, HypeBondError::AuctionAlreadySettled);
+
+        let mut cumulative_sol: u64 = 0;
+        let mut cleared = false;
+
+        for level in (0..BID_PRICE_LEVELS).rev() {
+            cumulative_sol = cumulative_sol
+                .checked_add(self.level_sol[level])
+                .ok_or(HypeBondError::MathOverflow)?;
+            let price = Self::level_price(curve, level as u8)?;
+            let demand = tokens_for_sol(cumulative_sol, price)?;
+
+            if demand >= supply as u128 && demand > 0 {
+                self.clearing_level = level as u8;
+                self.clearing_price = price;
+                self.filled_sol = cumulative_sol;
+                self.filled_tokens = supply;
+                cleared = true;
+                break;
+            }
+        }
+
+        if !cleared {
+            let price = Self::level_price(curve, 0)?;
+            let demand = tokens_for_sol(self.total_bid_sol, price)?;
+            self.clearing_level = 0;
+            self.clearing_price = price;
+            self.filled_sol = self.total_bid_sol;
+            self.filled_tokens = math::to_u64(demand)?;
+        }
+
+        // Demand covers the supply, so this never exceeds filled_sol
+        self.filled_cost = math::tokens_to_sol(self.filled_tokens, self.clearing_price, Rounding::Up)?
+            .min(self.filled_sol);
+        self.reserved_tokens = self.filled_tokens;
+
+        self.settled = true;
+        Ok(())
+    }
+
+    // Unwind a settlement that missed the curve's reserve: every bid is refunded
+    pub fn cancel_fills(&mut self) {
+        self.filled_tokens = 0;
+        self.filled_cost = 0;
+        self.reserved_tokens = 0;
+    }
+
+    // Tokens filled and lamports charged for a bid. Tokens round down and
+    // cost rounds up, so claims never pay out more than settlement reserved
+    pub fn fill_for(&self, bid: &Bid) -> Result<(u64, u64)> {
+        require!(self.settled, HypeBondError::AuctionNotSettled);
+
+        if bid.level < self.clearing_level || self.filled_tokens == 0 || self.filled_sol == 0 {
+            return Ok((0, 0));
+        }
+
+        let tokens = math::mul_div(bid.sol_amount, self.filled_tokens, self.filled_sol, Rounding::Down)?;
+        let cost = math::mul_div(bid.sol_amount, self.filled_cost, self.filled_sol, Rounding::Up)?;
+
+        Ok((tokens, cost.min(bid.sol_amount)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curve(start_price: u64, end_price: u64) -> BondingCurve {
+        BondingCurve {
+            discovery_start_price: start_price,
+            discovery_end_price: end_price,
+            ..Default::default()
+        }
+    }
+
+    fn bid(level: u8, sol_amount: u64) -> Bid {
+        Bid { level, sol_amount, ..Default::default() }
+    }
+
+    // Settle a book and check every fill stays within what settlement reserved
+    fn settle(curve: &BondingCurve, supply: u64, bids: &[Bid]) -> BidBook {
+        let mut book = BidBook::default();
+        for bid in bids {
+            book.add_bid(bid.level, bid.sol_amount).unwrap();
+        }
+        book.settle(curve, supply).unwrap();
+
+        let (mut tokens, mut cost) = (0u64, 0u64);
+        for bid in bids {
+            let (filled, charged) = book.fill_for(bid).unwrap();
+            assert!(charged <= bid.sol_amount);
+            tokens += filled;
+            cost += charged;
+        }
+        assert!(tokens <= book.reserved_tokens);
+        assert!(cost >= book.filled_cost);
+        book
+    }
+
+    #[test]
+    fn oversubscribed_book_clears_pro_rata() {
+        let curve = curve(31 * PRICE_PRECISION, PRICE_PRECISION);
+        let bids = [bid(31, 40 * PRICE_PRECISION), bid(31, 20 * PRICE_PRECISION), bid(0, 5 * PRICE_PRECISION)];
+        let book = settle(&curve, 1, &bids);
+
+        assert_eq!(book.clearing_level, 31);
+        assert_eq!(book.clearing_price, 31 * PRICE_PRECISION);
+        assert_eq!(book.filled_sol, 60 * PRICE_PRECISION);
+        assert_eq!(book.fill_for(&bids[2]).unwrap(), (0, 0));
+    }
+
+    #[test]
+    fn undersubscribed_book_fills_everyone_at_the_floor() {
+        let curve = curve(2_000, 1_000);
+        let bids = [bid(5, 1_000_000), bid(0, 3_000_001)];
+        let book = settle(&curve, u64::MAX, &bids);
+
+        assert_eq!(book.clearing_level, 0);
+        assert_eq!(book.clearing_price, 1_000);
+        assert_eq!(book.filled_tokens, 4_000_001_000_000);
+        assert_eq!(book.filled_cost, 4_000_001);
+        assert_eq!(book.fill_for(&bids[0]).unwrap(), (1_000_000_000_000, 1_000_000));
+    }
+
+    #[test]
+    fn low_prices_settle_without_overflow() {
+        // 20 SOL at 1 lamport per PRICE_PRECISION tokens is 2e19 tokens of demand
+        let curve = curve(32, 1);
+        let bids = [bid(0, 15_000_000_000), bid(0, 5_000_000_000)];
+        let book = settle(&curve, 800_000_000_000_000, &bids);
+
+        assert_eq!(book.filled_tokens, 800_000_000_000_000);
+        assert_eq!(book.fill_for(&bids[0]).unwrap().0, 600_000_000_000_000);
+    }
+
+    #[test]
+    fn cancelled_fills_refund_everything() {
+        let curve = curve(2_000, 1_000);
+        let bids = [bid(3, 1_000_000)];
+        let mut book = settle(&curve, 1_000, &bids);
+        book.cancel_fills();
+        assert_eq!(book.fill_for(&bids[0]).unwrap(), (0, 0));
+    }
+}
@@ -2,4 +2,10 @@ pub const SMALL_TRADE_THRESHOLD: u64 = 1000;
 pub const MEDIUM_TRADE_THRESHOLD: u64 = 10000;
 // pub const LARGE_TRADE_THRESHOLD: u64 = 100000;
 pub const VOLUME_THRESHOLD: u64 = 1_000_000;
-pub const MAX_SLIPPAGE: u64 = 120; 
\ No newline at end of file
+pub const MAX_SLIPPAGE: u64 = 120; 
+pub const MAX_PRICE_BREAKPOINTS: usize = 8;
+pub const PRICE_PRECISION: u64 = 1_000_000_000; // Prices are lamports per 1e9 token units
+pub const BID_PRICE_LEVELS: usize = 32;
+pub const VOLUME_BUCKET_COUNT: usize = 12;
+pub const VOLUME_BUCKET_DURATION: i64 = 300; // 12 x 5 minute buckets = trailing hour
+pub const MAX_TEAM_MEMBERS: usize = 8;
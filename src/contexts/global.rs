@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use super::protection_policy::ProtectionPolicy;
 
 #[account]
 #[derive(Default)]
@@ -16,4 +17,5 @@ pub struct Global {
     pub max_trade_amount: u64,
     pub base_slippage: u64,
     pub volume_multiplier: u64,
+    pub protection_policy: ProtectionPolicy,
 }
\ No newline at end of file
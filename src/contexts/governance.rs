@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::errors::HypeBondError;
+use crate::math::{self, Rounding};
+
+// How holders approve milestone completions
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct GovernanceConfig {
+    pub voting_period: i64,         // Seconds a completion proposal stays open
+    pub quorum_bps: u16,            // Share of circulating supply that must vote, non-zero
+    pub approval_bps: u16,          // Share of cast votes that must approve, above 50%
+}
+
+impl GovernanceConfig {
+    pub const SIZE: usize = 8 + 2 + 2;
+
+    pub fn is_valid(&self) -> bool {
+        self.voting_period > 0
+            && self.quorum_bps > 0
+            && self.quorum_bps <= 10000
+            && self.approval_bps > 5000
+            && self.approval_bps <= 10000
+    }
+}
+
+// A vote on whether one milestone has been delivered
+#[account]
+#[derive(Default)]
+pub struct MilestoneProposal {
+    pub project: Pubkey,
+    pub milestone: Pubkey,
+    pub proposal_id: u8,
+    pub proposer: Pubkey,
+    pub eligible_supply: u64,       // Tokens outside the vault, curve and pool when opened
+    pub quorum_bps: u16,            // Thresholds snapshotted from the project
+    pub approval_bps: u16,
+    pub voting_start: i64,
+    pub voting_end: i64,
+    pub yes_votes: u64,             // Tokens deposited in favor
+    pub no_votes: u64,              // Tokens deposited against
+}
+
+impl MilestoneProposal {
+    pub fn is_active(&self, current_time: i64) -> bool {
+        current_time >= self.voting_start && current_time < self.voting_end
+    }
+
+    pub fn has_ended(&self, current_time: i64) -> bool {
+        current_time >= self.voting_end
+    }
+
+    pub fn record_vote(&mut self, approve: bool, amount: u64) -> Result<()> {
+        let tally = if approve { &mut self.yes_votes } else { &mut self.no_votes };
+        *tally = tally.checked_add(amount).ok_or(HypeBondError::MathOverflow)?;
+        Ok(())
+    }
+
+    // Quorum against the eligible supply, approval against votes cast
+    pub fn has_passed(&self) -> Result<bool> {
+        let total_votes = self.yes_votes
+            .checked_add(self.no_votes)
+            .ok_or(HypeBondError::MathOverflow)?;
+        let quorum = math::apply_bps(self.eligible_supply, self.quorum_bps as u64, Rounding::Up)?;
+        let approval = math::apply_bps(total_votes, self.approval_bps as u64, Rounding::Up)?;
+
+        Ok(self.yes_votes > 0 && total_votes >= quorum && self.yes_votes >= approval)
+    }
+}
+
+// Tokens one holder deposited on a proposal, returned once voting ends
+#[account]
+#[derive(Default)]
+pub struct VoteRecord {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub approve: bool,
+    pub amount: u64,
+}
@@ -10,4 +10,16 @@ pub struct Milestone {
     pub total_tokens: u64,
     pub team_tokens: u64,
     pub treasury_tokens: u64,
+    pub deadline: i64,              // Unix time to deliver by, 0 means no deadline
+    pub team_sol: u64,              // Escrowed lamports earmarked for the team on completion
+    pub completed_at: i64,
+    pub proposal_count: u8,         // Completion proposals opened so far
+    pub last_proposal: Pubkey,      // Most recent completion proposal, default if none
+    pub voting_ends_at: i64,        // End of the most recent proposal's vote
+}
+
+impl Milestone {
+    pub fn is_overdue(&self, current_time: i64) -> bool {
+        !self.completed && self.deadline > 0 && current_time > self.deadline
+    }
 }
\ No newline at end of file
@@ -3,9 +3,21 @@ mod milestone;
 mod team_member;
 mod token_details;
 mod global;
+mod sol_vault;
+mod protection_policy;
+mod user_position;
+mod project_escrow;
+mod vesting;
+mod governance;
 
 pub use project::*;
 pub use milestone::*;
 pub use team_member::*;
 pub use token_details::*;
-pub use global::*;
\ No newline at end of file
+pub use global::*;
+pub use sol_vault::*;
+pub use protection_policy::*;
+pub use user_position::*;
+pub use project_escrow::*;
+pub use vesting::*;
+pub use governance::*;
\ No newline at end of file
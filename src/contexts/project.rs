@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 use super::team_member::TeamAllocation;
+use super::vesting::VestingConfig;
+use super::governance::GovernanceConfig;
 
 #[account]
 pub struct Project {
@@ -11,4 +13,8 @@ pub struct Project {
     pub completed_milestones: u8,
     pub unlock_per_milestone: u64,  // In basis points (100.00%)
     pub team_allocation: TeamAllocation,
+    pub abandoned: bool,            // A milestone deadline passed; holders may redeem
+    pub team_vesting: VestingConfig,
+    pub governance: GovernanceConfig,  // Holder vote required to complete milestones
+    pub team_tokens_owed: u64,      // Earned on completed milestones but still in the vault
 }
\ No newline at end of file
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::errors::HypeBondError;
+use crate::math::{self, Rounding};
+
+// Program-owned lamport holder for the project's share of curve proceeds.
+// SOL is released to the team in step with completed milestones.
+#[account]
+#[derive(Default)]
+pub struct ProjectEscrow {
+    pub project: Pubkey,
+    pub total_deposited: u64,
+    pub total_released: u64,
+    pub total_redeemed: u64,        // Paid out to holders of an abandoned project
+    pub redeemable_supply: u64,     // Tokens left to redeem, snapshotted at abandonment
+}
+
+impl ProjectEscrow {
+    pub fn deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_deposited = self.total_deposited
+            .checked_add(amount)
+            .ok_or(HypeBondError::MathOverflow)?;
+        Ok(())
+    }
+
+    // Unreleased share of deposits unlocked by the milestones completed so far.
+    // Cumulative, so milestones completed before funding catch up later.
+    pub fn releasable(&self, completed_milestones: u8, total_milestones: u8) -> Result<u64> {
+        require!(total_milestones > 0, HypeBondError::InvalidMilestoneCount);
+
+        let unlocked = math::mul_div(
+            self.total_deposited,
+            completed_milestones.min(total_milestones) as u64,
+            total_milestones as u64,
+            Rounding::Down,
+        )?;
+        Ok(unlocked.saturating_sub(self.total_released))
+    }
+
+    pub fn remaining(&self) -> u64 {
+        self.total_deposited
+            .saturating_sub(self.total_released)
+            .saturating_sub(self.total_redeemed)
+    }
+
+    // Pro-rata share of the remaining escrow for burning token_amount of the
+    // redeemable supply. Both shrink together, so the rate stays fixed
+    pub fn redemption_amount(&self, token_amount: u64) -> Result<u64> {
+        require!(
+            token_amount > 0 && token_amount <= self.redeemable_supply,
+            HypeBondError::InvalidTradeSize
+        );
+        math::mul_div(token_amount, self.remaining(), self.redeemable_supply, Rounding::Down)
+    }
+
+    pub fn redeem(&mut self, token_amount: u64, sol_amount: u64) -> Result<()> {
+        self.redeemable_supply = self.redeemable_supply
+            .checked_sub(token_amount)
+            .ok_or(HypeBondError::MathOverflow)?;
+        self.total_redeemed = self.total_redeemed
+            .checked_add(sol_amount)
+            .ok_or(HypeBondError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn release(&mut self, amount: u64) -> Result<()> {
+        self.total_released = self.total_released
+            .checked_add(amount)
+            .ok_or(HypeBondError::MathOverflow)?;
+        Ok(())
+    }
+}
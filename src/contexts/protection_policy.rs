@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::constants::{
+    MAX_SLIPPAGE,
+    MEDIUM_TRADE_THRESHOLD,
+    SMALL_TRADE_THRESHOLD,
+    VOLUME_THRESHOLD
+};
+
+// Anti-whale and slippage settings applied by curve pricing. Held in Global
+// and optionally overridden per curve; defaults match the original constants.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ProtectionPolicy {
+    pub small_trade_threshold: u64,
+    pub medium_trade_threshold: u64,
+    pub volume_threshold: u64,            // Volume per step of dynamic slippage
+    pub max_slippage: u64,                // Cap on the dynamic multiplier, at least 100 (1x)
+    pub buy_tier_multipliers: [u64; 3],   // Percent of base cost for small/medium/large buys
+    pub sell_tier_multipliers: [u64; 3],  // Percent of base output for small/medium/large sells
+    pub discovery_sell_bps: u64,          // Share of value paid out on discovery sells
+}
+
+impl Default for ProtectionPolicy {
+    fn default() -> Self {
+        ProtectionPolicy {
+            small_trade_threshold: SMALL_TRADE_THRESHOLD,
+            medium_trade_threshold: MEDIUM_TRADE_THRESHOLD,
+            volume_threshold: VOLUME_THRESHOLD,
+            max_slippage: MAX_SLIPPAGE,
+            buy_tier_multipliers: [101, 103, 105],  // 1% / 3% / 5% slippage
+            sell_tier_multipliers: [99, 97, 95],    // 1% / 3% / 5% penalty
+            discovery_sell_bps: 9500,               // 5% penalty
+        }
+    }
+}
+
+impl ProtectionPolicy {
+    pub fn is_valid(&self) -> bool {
+        self.small_trade_threshold <= self.medium_trade_threshold
+            && self.volume_threshold > 0
+            && self.max_slippage >= 100
+            && self.buy_tier_multipliers.iter().all(|m| *m >= 100)
+            && self.sell_tier_multipliers.iter().all(|m| *m > 0 && *m <= 100)
+            && self.discovery_sell_bps <= 10000
+    }
+
+    fn tier(&self, amount: u64) -> usize {
+        if amount < self.small_trade_threshold {
+            0
+        } else if amount < self.medium_trade_threshold {
+            1
+        } else {
+            2
+        }
+    }
+
+    pub fn buy_multiplier(&self, amount: u64) -> u64 {
+        self.buy_tier_multipliers[self.tier(amount)]
+    }
+
+    pub fn sell_multiplier(&self, amount: u64) -> u64 {
+        self.sell_tier_multipliers[self.tier(amount)]
+    }
+}
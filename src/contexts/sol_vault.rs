@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::errors::HypeBondError;
+
+// Program-owned lamport holder backing the SOL reserves of a curve or pool
+#[account]
+#[derive(Default)]
+pub struct SolVault {
+    pub owner: Pubkey,  // Bonding curve or pool this vault belongs to
+}
+
+impl SolVault {
+    // Lamports held above the rent-exempt minimum
+    pub fn available_lamports(vault: &AccountInfo) -> Result<u64> {
+        let rent_exempt = Rent::get()?.minimum_balance(vault.data_len());
+        vault.lamports()
+            .checked_sub(rent_exempt)
+            .ok_or(HypeBondError::InsufficientVaultBalance.into())
+    }
+
+    // Debit lamports directly, never dipping into the rent-exempt reserve
+    pub fn withdraw(vault: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+        require!(
+            Self::available_lamports(vault)? >= amount,
+            HypeBondError::InsufficientVaultBalance
+        );
+
+        **vault.try_borrow_mut_lamports()? -= amount;
+        **to.try_borrow_mut_lamports()? += amount;
+        Ok(())
+    }
+
+    // Ensure the on-chain balance actually covers the tracked reserves
+    pub fn check_reserves(vault: &AccountInfo, real_sol_reserves: u64) -> Result<()> {
+        require!(
+            Self::available_lamports(vault)? >= real_sol_reserves,
+            HypeBondError::InsufficientVaultBalance
+        );
+        Ok(())
+    }
+}
@@ -1,7 +1,67 @@
 use anchor_lang::prelude::*;
+use crate::constants::MAX_TEAM_MEMBERS;
+use crate::math::{self, Rounding};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum TeamRole {
+    Founder,
+    Engineer,
+    Advisor,
+    Operations,
+    Other,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TeamMember {
+    pub wallet: Pubkey,
+    pub share_bps: u16,             // Share of each milestone's team payout
+    pub role: Option<TeamRole>,
+}
+
+impl TeamMember {
+    pub const SIZE: usize = 32 + 2 + 2;
+}
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct TeamAllocation {
-    pub wallet: Pubkey,
+    pub members: Vec<TeamMember>,   // Up to MAX_TEAM_MEMBERS, shares summing to 10000
     pub percentage: u8,    // Percentage of team allocation
-}
\ No newline at end of file
+}
+
+impl TeamAllocation {
+    pub const SIZE: usize = 4 + MAX_TEAM_MEMBERS * TeamMember::SIZE + 1;
+
+    pub fn is_valid(&self) -> bool {
+        let total_bps: u32 = self.members.iter().map(|m| m.share_bps as u32).sum();
+        let unique = self.members.iter().enumerate().all(|(i, member)| {
+            self.members[..i].iter().all(|other| other.wallet != member.wallet)
+        });
+
+        !self.members.is_empty()
+            && self.members.len() <= MAX_TEAM_MEMBERS
+            && self.members.iter().all(|m| m.share_bps > 0)
+            && total_bps == 10000
+            && unique
+            && self.percentage <= 100
+    }
+
+    pub fn member(&self, wallet: &Pubkey) -> Option<&TeamMember> {
+        self.members.iter().find(|m| m.wallet == *wallet)
+    }
+
+    // A member's cut of a team payout, rounded down so shares never overdraw
+    pub fn member_amount(member: &TeamMember, total: u64) -> Result<u64> {
+        math::apply_bps(total, member.share_bps as u64, Rounding::Down)
+    }
+}
+
+// What one team member has pulled from a completed milestone
+#[account]
+#[derive(Default)]
+pub struct TeamMemberClaim {
+    pub milestone: Pubkey,
+    pub member: Pubkey,
+    pub tokens_claimed: u64,
+    pub sol_claimed: u64,
+    pub stream_id: Pubkey,          // Streamflow stream vesting this member's tokens, if any
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::errors::HypeBondError;
+use crate::math::{self, Rounding};
+
+// A wallet's trading history against one bonding curve
+#[account]
+#[derive(Default)]
+pub struct UserPosition {
+    pub bonding_curve: Pubkey,
+    pub user: Pubkey,
+    pub tokens_bought: u64,
+    pub tokens_sold: u64,
+    pub sol_spent: u64,             // Lamports paid to the curve, excluding fees
+    pub sol_received: u64,          // Lamports received from the curve, before fees
+    pub discovery_tokens_bought: u64,  // Counted against the discovery wallet cap
+    pub allowlist_tokens_bought: u64,  // Counted against the allowlist allocation
+    pub token_balance: u64,         // Tokens bought from the curve and not yet sold back
+    pub cost_basis: u64,            // Lamports paid for token_balance
+}
+
+impl UserPosition {
+    pub fn record_buy(&mut self, token_amount: u64, sol_amount: u64, in_discovery: bool) -> Result<()> {
+        self.tokens_bought = self.tokens_bought
+            .checked_add(token_amount)
+            .ok_or(HypeBondError::MathOverflow)?;
+        self.sol_spent = self.sol_spent
+            .checked_add(sol_amount)
+            .ok_or(HypeBondError::MathOverflow)?;
+        self.token_balance = self.token_balance
+            .checked_add(token_amount)
+            .ok_or(HypeBondError::MathOverflow)?;
+        self.cost_basis = self.cost_basis
+            .checked_add(sol_amount)
+            .ok_or(HypeBondError::MathOverflow)?;
+
+        if in_discovery {
+            self.discovery_tokens_bought = self.discovery_tokens_bought
+                .checked_add(token_amount)
+                .ok_or(HypeBondError::MathOverflow)?;
+        }
+        Ok(())
+    }
+
+    pub fn record_allowlist_buy(&mut self, token_amount: u64, allocation: u64) -> Result<()> {
+        self.allowlist_tokens_bought = self.allowlist_tokens_bought
+            .checked_add(token_amount)
+            .ok_or(HypeBondError::MathOverflow)?;
+        require!(
+            self.allowlist_tokens_bought <= allocation,
+            HypeBondError::AllowlistAllocationExceeded
+        );
+        Ok(())
+    }
+
+    // Release cost basis pro rata; tokens acquired elsewhere carry no basis
+    pub fn record_sell(&mut self, token_amount: u64, sol_amount: u64) -> Result<()> {
+        self.tokens_sold = self.tokens_sold
+            .checked_add(token_amount)
+            .ok_or(HypeBondError::MathOverflow)?;
+        self.sol_received = self.sol_received
+            .checked_add(sol_amount)
+            .ok_or(HypeBondError::MathOverflow)?;
+
+        let sold_from_balance = token_amount.min(self.token_balance);
+        if sold_from_balance > 0 {
+            let released = math::mul_div(self.cost_basis, sold_from_balance, self.token_balance, Rounding::Up)?;
+            self.cost_basis = self.cost_basis.saturating_sub(released);
+            self.token_balance -= sold_from_balance;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use crate::errors::HypeBondError;
+use crate::math::{self, Rounding};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VestingMode {
+    #[default]
+    Streamflow,     // Stream created through a Streamflow CPI
+    Native,         // VestingSchedule account claimed from the project vault
+}
+
+// How team tokens unlocked by a milestone vest; a zero duration pays them out directly
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct VestingConfig {
+    pub mode: VestingMode,
+    pub cliff_seconds: u64,         // Delay after completion before anything unlocks
+    pub duration_seconds: u64,      // Linear vesting length after the cliff
+    pub period_seconds: u64,        // Release frequency
+}
+
+impl VestingConfig {
+    pub const SIZE: usize = 1 + 8 + 8 + 8;
+
+    pub fn is_enabled(&self) -> bool {
+        self.duration_seconds > 0
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.is_enabled()
+            || (self.period_seconds > 0 && self.period_seconds <= self.duration_seconds)
+    }
+}
+
+// Team tokens for one milestone vesting out of the project vault, shared
+// between members by their share_bps
+#[account]
+#[derive(Default)]
+pub struct VestingSchedule {
+    pub project: Pubkey,
+    pub milestone: Pubkey,
+    pub start_time: i64,            // Linear release begins
+    pub cliff_time: i64,            // Nothing is claimable before this
+    pub end_time: i64,              // Fully vested
+    pub period_seconds: i64,        // Release frequency
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+}
+
+impl VestingSchedule {
+    // Lay out a schedule matching the Streamflow stream: linear after the cliff
+    pub fn initialize(
+        &mut self,
+        project: Pubkey,
+        milestone: Pubkey,
+        config: &VestingConfig,
+        total_amount: u64,
+        current_time: i64,
+    ) -> Result<()> {
+        let cliff_time = current_time
+            .checked_add(config.cliff_seconds as i64)
+            .ok_or(HypeBondError::MathOverflow)?;
+
+        self.project = project;
+        self.milestone = milestone;
+        self.start_time = cliff_time;
+        self.cliff_time = cliff_time;
+        self.end_time = cliff_time
+            .checked_add(config.duration_seconds as i64)
+            .ok_or(HypeBondError::MathOverflow)?;
+        self.period_seconds = config.period_seconds as i64;
+        self.total_amount = total_amount;
+        self.claimed_amount = 0;
+        Ok(())
+    }
+
+    // Tokens vested by current_time, released in whole periods
+    pub fn vested_amount(&self, current_time: i64) -> Result<u64> {
+        if current_time < self.cliff_time {
+            return Ok(0);
+        }
+        if current_time >= self.end_time {
+            return Ok(self.total_amount);
+        }
+
+        let elapsed = current_time - self.start_time;
+        let released = elapsed - elapsed % self.period_seconds.max(1);
+        math::mul_div(
+            self.total_amount,
+            released as u64,
+            (self.end_time - self.start_time) as u64,
+            Rounding::Down,
+        )
+    }
+
+    // Claim a member's newly vested share given what they already took
+    pub fn claim_share(&mut self, current_time: i64, share_bps: u16, member_claimed: u64) -> Result<u64> {
+        let vested = math::apply_bps(self.vested_amount(current_time)?, share_bps as u64, Rounding::Down)?;
+        let claimable = vested.saturating_sub(member_claimed);
+        require!(claimable > 0, HypeBondError::NothingToClaim);
+
+        self.claimed_amount = self.claimed_amount
+            .checked_add(claimable)
+            .ok_or(HypeBondError::MathOverflow)?;
+        Ok(claimable)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule() -> VestingSchedule {
+        let config = VestingConfig {
+            mode: VestingMode::Native,
+            cliff_seconds: 100,
+            duration_seconds: 1_000,
+            period_seconds: 250,
+        };
+        let mut schedule = VestingSchedule::default();
+        schedule.initialize(Pubkey::default(), Pubkey::default(), &config, 1_001, 0).unwrap();
+        schedule
+    }
+
+    #[test]
+    fn vests_in_whole_periods_after_the_cliff() {
+        let schedule = schedule();
+        assert_eq!(schedule.vested_amount(99).unwrap(), 0);
+        assert_eq!(schedule.vested_amount(349).unwrap(), 0);
+        assert_eq!(schedule.vested_amount(350).unwrap(), 250);      // 250.25 rounds down
+        assert_eq!(schedule.vested_amount(1_099).unwrap(), 750);
+        assert_eq!(schedule.vested_amount(1_100).unwrap(), 1_001);
+    }
+
+    #[test]
+    fn member_shares_never_overdraw() {
+        let mut schedule = schedule();
+        let first = schedule.claim_share(1_100, 3_333, 0).unwrap();
+        let second = schedule.claim_share(1_100, 6_667, 0).unwrap();
+        assert_eq!((first, second), (333, 667));
+        assert!(schedule.claimed_amount <= schedule.total_amount);
+        assert!(schedule.claim_share(1_100, 3_333, first).is_err());
+    }
+}
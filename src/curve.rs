@@ -1,23 +1,118 @@
 use anchor_lang::prelude::*;
 use crate::constants::{
-    MAX_SLIPPAGE, 
-    MEDIUM_TRADE_THRESHOLD, 
-    SMALL_TRADE_THRESHOLD, 
-    VOLUME_THRESHOLD
+    MAX_PRICE_BREAKPOINTS,
+    PRICE_PRECISION,
+    VOLUME_BUCKET_COUNT,
+    VOLUME_BUCKET_DURATION
 };
+use crate::contexts::ProtectionPolicy;
 use crate::errors::HypeBondError;
-use crate::constant;
+use crate::math::{self, Rounding, mul_div, mul_div_u128, to_u64};
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Default)]
 pub enum CurveState {
+    #[default]
     PriceDiscovery,  // Initial phase - price decreases over time
-    BondingCurve     // Second phase - traditional x*y=k bonding curve
+    BondingCurve,    // Second phase - traditional x*y=k bonding curve
+    Failed           // Discovery missed its reserve, buyers can reclaim SOL
 }
 
-impl Default for CurveState {
-    fn default() -> Self {
-        CurveState::PriceDiscovery
+// Traded volume within one fixed interval of the rolling window
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VolumeBucket {
+    pub interval: i64,     // timestamp / VOLUME_BUCKET_DURATION this bucket covers
+    pub buy_volume: u64,
+    pub sell_volume: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PriceBreakpoint {
+    pub time_offset: i64,  // Seconds since discovery start
+    pub price: u64,        // Price in lamports per token at this offset
+}
+
+// Shape of the discovery price path between start and end price
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DecayMode {
+    #[default]
+    Linear,
+    Exponential { half_life: i64 },   // Distance to end price halves every half_life seconds
+    Stepwise { step_duration: i64 },  // Price holds, then drops one step every step_duration seconds
+    Piecewise {                       // Linear segments through custom breakpoints
+        breakpoints: [PriceBreakpoint; MAX_PRICE_BREAKPOINTS],
+        count: u8,
+    },
+}
+
+impl DecayMode {
+    // Validate mode parameters against the discovery window
+    pub fn is_valid(&self, duration: i64, start_price: u64, end_price: u64) -> bool {
+        match self {
+            DecayMode::Linear => true,
+            DecayMode::Exponential { half_life } => *half_life > 0,
+            DecayMode::Stepwise { step_duration } => {
+                *step_duration > 0 && *step_duration <= duration
+            },
+            DecayMode::Piecewise { breakpoints, count } => {
+                if *count as usize > MAX_PRICE_BREAKPOINTS {
+                    return false;
+                }
+                // Offsets strictly increase inside the window, prices never increase
+                let mut prev = PriceBreakpoint { time_offset: 0, price: start_price };
+                for point in &breakpoints[..*count as usize] {
+                    if point.time_offset <= prev.time_offset
+                        || point.time_offset >= duration
+                        || point.price > prev.price
+                        || point.price < end_price
+                    {
+                        return false;
+                    }
+                    prev = *point;
+                }
+                true
+            }
+        }
+    }
+}
+
+// Linear interpolation between two points of the price path
+fn interpolate(from: PriceBreakpoint, to: PriceBreakpoint, offset: i64) -> Result<u64> {
+    let span = to.time_offset.saturating_sub(from.time_offset);
+    if span <= 0 {
+        return Ok(to.price);
     }
+    let progress = offset.clamp(from.time_offset, to.time_offset) - from.time_offset;
+    let range = from.price.abs_diff(to.price);
+    let delta = mul_div(range, progress as u64, span as u64, Rounding::Down)?;
+
+    if to.price <= from.price {
+        Ok(from.price - delta)
+    } else {
+        Ok(from.price + delta)
+    }
+}
+
+// How tokens are sold during the discovery window
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiscoveryMode {
+    #[default]
+    Continuous,   // Buys execute immediately at the instantaneous discovery price
+    BatchAuction  // Bids are collected and settled at a single uniform price
+}
+
+// How the bonding phase opening price is derived from discovery activity
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClearingPriceMode {
+    #[default]
+    VolumeWeighted,  // Average price paid across all discovery buys
+    LastTrade        // Price of the final discovery trade
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompletionMode {
+    #[default]
+    TokensSold,  // Complete once this many tokens have left the curve
+    SolRaised    // Complete once real SOL reserves reach the threshold
 }
 
 #[account]
@@ -32,12 +127,12 @@ pub struct BondingCurve {
     pub complete: bool,
 
     // Protection parameters
-    pub last_hour_volume: u64,
-    pub last_hour_timestamp: i64,
+    pub volume_buckets: [VolumeBucket; VOLUME_BUCKET_COUNT],  // Ring buffer of recent volume
     pub min_trade_amount: u64,
     pub max_trade_amount: u64,
     pub base_slippage: u64,
     pub volume_multiplier: u64,
+    pub protection_override: Option<ProtectionPolicy>,  // Replaces the global policy when set
     
     // Doppler two-phase approach parameters
     pub curve_state: CurveState,
@@ -45,199 +140,531 @@ pub struct BondingCurve {
     pub discovery_duration: i64,
     pub discovery_start_price: u64,
     pub discovery_end_price: u64,
+    pub decay_mode: DecayMode,
+    pub discovery_mode: DiscoveryMode,
+    pub auction_token_supply: u64,    // Tokens offered in a batch auction
+    pub clearing_price_mode: ClearingPriceMode,
     pub clearing_price: u64,
+    pub reserve_price: u64,           // Minimum acceptable clearing price, 0 disables
+    pub min_sol_raise: u64,           // Soft cap on SOL raised in discovery, 0 disables
+    pub discovery_sol_volume: u64,    // Lamports paid by discovery buys
+    pub discovery_token_volume: u64,  // Tokens bought during discovery
+    pub last_trade_price: u64,
+
+    // Adaptive rebalancing: shift the discovery path toward observed demand
+    pub rebalance_epoch_duration: i64,  // 0 disables rebalancing
+    pub target_tokens_per_epoch: u64,
+    pub rebalance_step_bps: u64,        // Shift per epoch, in bps of the discovery price range
+    pub current_epoch: i64,
+    pub epoch_tokens_sold: u64,
+    pub price_offset: i64,              // Cumulative shift applied to the discovery path
+
+    // Graduation parameters
+    pub completion_mode: CompletionMode,
+    pub completion_threshold: u64,
+
+    // Per-wallet limits, 0 disables each
+    pub discovery_wallet_cap: u64,  // Tokens one wallet may buy during discovery
+    pub max_wallet_tokens: u64,     // Tokens one wallet may hold after a bonding phase buy
+
+    // Allowlist window at the start of discovery
+    pub allowlist_root: [u8; 32],   // Merkle root of (wallet, allocation) leaves
+    pub allowlist_duration: i64,    // Seconds only allowlisted wallets can buy, 0 disables
+
+    // Project funding
+    pub project: Pubkey,
+    pub escrow_share_bps: u64,      // Share of raised SOL escrowed for the team at graduation
 }
 
 impl BondingCurve {
-    // Core price calculation using constant product formula
+    // Core price calculation using constant product formula. Buys take tokens
+    // out of the curve and sells put them back; post-trade SOL reserves are
+    // rounded up so k never shrinks on either side
     pub fn get_base_price(&self, amount: u64, is_buy: bool) -> Result<u64> {
-        let k = self.virtual_token_reserves
-            .checked_mul(self.virtual_sol_reserves)
-            .ok_or(HypeBondError::MathOverflow)?;
+        let k = (self.virtual_token_reserves as u128) * (self.virtual_sol_reserves as u128);
 
         if is_buy {
             let new_token_reserves = self.virtual_token_reserves
-                .checked_add(amount)
+                .checked_sub(amount)
+                .filter(|reserves| *reserves > 0)
                 .ok_or(HypeBondError::MathOverflow)?;
             
-            let new_sol_reserves = k / new_token_reserves;
+            let new_sol_reserves = to_u64(k.div_ceil(new_token_reserves as u128))?;
             
-            self.virtual_sol_reserves
-                .checked_sub(new_sol_reserves)
+            new_sol_reserves
+                .checked_sub(self.virtual_sol_reserves)
                 .ok_or(HypeBondError::MathOverflow.into())
         } else {
             let new_token_reserves = self.virtual_token_reserves
-                .checked_sub(amount)
+                .checked_add(amount)
                 .ok_or(HypeBondError::MathOverflow)?;
             
-            let new_sol_reserves = k / new_token_reserves;
+            let new_sol_reserves = to_u64(k.div_ceil(new_token_reserves as u128))?;
             
-            new_sol_reserves
-                .checked_sub(self.virtual_sol_reserves)
+            self.virtual_sol_reserves
+                .checked_sub(new_sol_reserves)
                 .ok_or(HypeBondError::MathOverflow.into())
         }
     }
 
     // Get current price in discovery phase (decreasing over time)
     pub fn get_discovery_price(&self, current_time: i64) -> Result<u64> {
-        let elapsed = current_time.saturating_sub(self.discovery_start_time);
-        
-        // If discovery phase is complete, return the end price
-        if elapsed >= self.discovery_duration {
-            return Ok(self.discovery_end_price);
-        }
-        
-        // Calculate price decay based on elapsed time (linear decay)
+        // Clamp to the discovery window so the end of the path is the final price
+        let elapsed = current_time
+            .saturating_sub(self.discovery_start_time)
+            .clamp(0, self.discovery_duration);
+
+        let start = PriceBreakpoint { time_offset: 0, price: self.discovery_start_price };
+        let end = PriceBreakpoint { time_offset: self.discovery_duration, price: self.discovery_end_price };
         let price_range = self.discovery_start_price.saturating_sub(self.discovery_end_price);
-        let price_decay = price_range
-            .checked_mul(elapsed as u64)
-            .unwrap_or(0)
-            .checked_div(self.discovery_duration as u64)
-            .unwrap_or(0);
-        
-        Ok(self.discovery_start_price.saturating_sub(price_decay))
+
+        let price = match self.decay_mode {
+            DecayMode::Linear => interpolate(start, end, elapsed)?,
+            DecayMode::Exponential { half_life } => {
+                // Halve the remaining range per full half-life and interpolate within one
+                let halvings = elapsed / half_life;
+                let remaining = if halvings >= 64 { 0 } else { price_range >> halvings };
+                let partial = PriceBreakpoint { time_offset: 0, price: remaining };
+                let halved = PriceBreakpoint { time_offset: half_life, price: remaining / 2 };
+                self.discovery_end_price
+                    .checked_add(interpolate(partial, halved, elapsed % half_life)?)
+                    .ok_or(HypeBondError::MathOverflow)?
+            },
+            DecayMode::Stepwise { step_duration } => {
+                let total_steps = self.discovery_duration / step_duration;
+                let steps = (elapsed / step_duration).min(total_steps);
+                let stepped = PriceBreakpoint { time_offset: total_steps, price: self.discovery_end_price };
+                interpolate(start, stepped, steps)?
+            },
+            DecayMode::Piecewise { breakpoints, count } => {
+                let mut prev = start;
+                let mut next = end;
+                for point in &breakpoints[..count as usize] {
+                    if elapsed < point.time_offset {
+                        next = *point;
+                        break;
+                    }
+                    prev = *point;
+                }
+                interpolate(prev, next, elapsed)?
+            }
+        };
+
+        // Apply the demand-driven shift, never pricing at zero
+        let shifted = (price as i128 + self.price_offset as i128).max(1);
+        to_u64(shifted as u128)
+    }
+
+    // Close out elapsed epochs, moving the price path up when demand beats
+    // the target sale rate and down when it falls short
+    pub fn rebalance(&mut self, current_time: i64) -> Result<()> {
+        if self.rebalance_epoch_duration == 0 || self.curve_state != CurveState::PriceDiscovery {
+            return Ok(());
+        }
+
+        let elapsed = current_time
+            .saturating_sub(self.discovery_start_time)
+            .clamp(0, self.discovery_duration);
+        let epoch = elapsed / self.rebalance_epoch_duration;
+        if epoch <= self.current_epoch {
+            return Ok(());
+        }
+
+        let price_range = self.discovery_start_price
+            .saturating_sub(self.discovery_end_price)
+            .max(1) as i128;
+        let step = price_range * self.rebalance_step_bps as i128 / 10000;
+
+        // The epoch with recorded sales moves the path either way
+        let mut shift = match self.epoch_tokens_sold.cmp(&self.target_tokens_per_epoch) {
+            std::cmp::Ordering::Greater => step,
+            std::cmp::Ordering::Less => -step,
+            std::cmp::Ordering::Equal => 0,
+        };
+
+        // Any further epochs that passed without trades fell short of the target
+        if self.target_tokens_per_epoch > 0 {
+            let idle_epochs = (epoch - self.current_epoch - 1) as i128;
+            shift -= step * idle_epochs;
+        }
+
+        let offset = (self.price_offset as i128 + shift).clamp(-price_range, price_range);
+        self.price_offset = offset as i64;
+        self.current_epoch = epoch;
+        self.epoch_tokens_sold = 0;
+
+        Ok(())
     }
 
-    // Check and update curve state if needed
-    pub fn check_and_update_state(&mut self, current_time: i64) -> Result<()> {
+    // Check and update curve state if needed; returns true when discovery ended
+    pub fn check_and_update_state(&mut self, current_time: i64) -> Result<bool> {
+        self.rebalance(current_time)?;
+
+        // Batch auctions only leave discovery through settlement
         if self.curve_state == CurveState::PriceDiscovery && 
-           current_time >= self.discovery_start_time + self.discovery_duration {
+           self.discovery_mode == DiscoveryMode::Continuous &&
+           current_time >= self.discovery_end_time() {
             
-            // Transition to bonding curve phase
+            let clearing_price = self.discovery_clearing_price()?;
+            if self.misses_reserve(clearing_price, self.real_sol_reserves) {
+                self.curve_state = CurveState::Failed;
+                return Ok(true);
+            }
+
+            // Transition to bonding curve phase at the price buyers actually paid
             self.curve_state = CurveState::BondingCurve;
-            self.clearing_price = self.discovery_end_price;
-            
-            // Initialize virtual reserves to establish the k constant
-            // that gives us our clearing price as the starting point
-            let initial_token_amount = self.token_total_supply / 10; // 10% of supply
-            
-            // Set virtual reserves to create the desired k constant
-            self.virtual_token_reserves = self.token_total_supply - self.real_token_reserves;
-            
-            // Calculate virtual SOL reserves based on clearing price
-            // Formula: sol_reserves = (token_reserves * token_price) / amount_to_buy_for_1_sol
-            // We're setting up k so that buying 1 SOL worth would cost the clearing price
-            let sol_per_token = 1_000_000_000 / self.clearing_price; // lamports per token
-            self.virtual_sol_reserves = self.virtual_token_reserves
-                .checked_mul(sol_per_token)
-                .unwrap_or(0)
-                .checked_div(1_000_000_000)
-                .unwrap_or(0);
-            
-            msg!("Transitioned to bonding curve phase. Clearing price: {}", self.clearing_price);
+            self.clearing_price = clearing_price;
+            self.seed_virtual_reserves(self.clearing_price)?;
+            return Ok(true);
         }
         
+        Ok(false)
+    }
+
+    // Whether discovery fell short of the reserve price or minimum raise
+    pub fn misses_reserve(&self, clearing_price: u64, sol_raised: u64) -> bool {
+        (self.reserve_price > 0 && clearing_price < self.reserve_price)
+            || sol_raised < self.min_sol_raise
+    }
+
+    // Lamports returned for handing back token_amount after a failed launch,
+    // pro rata to the tokens still held outside the curve
+    pub fn refund_amount(&self, token_amount: u64) -> Result<u64> {
+        require!(self.curve_state == CurveState::Failed, HypeBondError::CurveNotFailed);
+
+        let outstanding = self.tokens_sold();
+        require!(token_amount > 0 && token_amount <= outstanding, HypeBondError::InvalidTradeSize);
+
+        mul_div(token_amount, self.real_sol_reserves, outstanding, Rounding::Down)
+    }
+
+    pub fn discovery_end_time(&self) -> i64 {
+        self.discovery_start_time.saturating_add(self.discovery_duration)
+    }
+
+    // Move a settled batch auction into the bonding curve phase, setting
+    // aside the tokens owed to filled bids
+    // Filled SOL backs the reserves from settlement, before bidders claim
+    pub fn complete_auction(&mut self, clearing_price: u64, reserved_tokens: u64, filled_cost: u64) -> Result<()> {
+        self.real_token_reserves = self.real_token_reserves
+            .checked_sub(reserved_tokens)
+            .ok_or(HypeBondError::MathOverflow)?;
+        self.real_sol_reserves = self.real_sol_reserves
+            .checked_add(filled_cost)
+            .ok_or(HypeBondError::MathOverflow)?;
+
+        self.curve_state = CurveState::BondingCurve;
+        self.clearing_price = clearing_price;
+        self.seed_virtual_reserves(clearing_price)?;
+        Ok(())
+    }
+
+    // Clearing price derived from discovery demand, falling back to the
+    // end of the price path when nobody traded
+    pub fn discovery_clearing_price(&self) -> Result<u64> {
+        let demand_price = match self.clearing_price_mode {
+            ClearingPriceMode::VolumeWeighted if self.discovery_token_volume > 0 => {
+                math::price_of(self.discovery_sol_volume, self.discovery_token_volume)?
+            },
+            ClearingPriceMode::LastTrade => self.last_trade_price,
+            _ => 0,
+        };
+
+        if demand_price > 0 {
+            Ok(demand_price)
+        } else {
+            self.get_discovery_price(self.discovery_end_time())
+        }
+    }
+
+    // Seed virtual reserves so the constant product spot price equals `price`
+    // exactly: virtual tokens are rounded up to a multiple that makes
+    // virtual_token_reserves * price divisible by PRICE_PRECISION
+    pub fn seed_virtual_reserves(&mut self, price: u64) -> Result<()> {
+        require!(price > 0, HypeBondError::InvalidDiscoveryParams);
+
+        let step = PRICE_PRECISION / math::gcd(price, PRICE_PRECISION);
+
+        let token_reserves = self.token_total_supply
+            .max(self.real_token_reserves)
+            .max(1)
+            .div_ceil(step)
+            .checked_mul(step)
+            .ok_or(HypeBondError::MathOverflow)?;
+
+        self.virtual_token_reserves = token_reserves;
+        self.virtual_sol_reserves = math::tokens_to_sol(token_reserves, price, Rounding::Down)?;
+
+        Ok(())
+    }
+
+    // Constant product spot price in lamports per PRICE_PRECISION tokens
+    pub fn spot_price(&self) -> Result<u64> {
+        math::price_of(self.virtual_sol_reserves, self.virtual_token_reserves)
+    }
+
+    // Price of the next token in whichever phase the curve is in
+    pub fn marginal_price(&self, current_time: i64) -> Result<u64> {
+        match self.curve_state {
+            CurveState::PriceDiscovery => self.get_discovery_price(current_time),
+            CurveState::BondingCurve => self.spot_price(),
+            CurveState::Failed => err!(HypeBondError::CurveFailed)
+        }
+    }
+
+    // Discovery start for a launch configured at current_time: now, or a
+    // scheduled time that has not already passed
+    pub fn scheduled_start(requested: Option<i64>, current_time: i64) -> Result<i64> {
+        match requested {
+            Some(start_time) => {
+                require!(start_time >= current_time, HypeBondError::InvalidDiscoveryParams);
+                Ok(start_time)
+            },
+            None => Ok(current_time)
+        }
+    }
+
+    pub fn check_started(&self, current_time: i64) -> Result<()> {
+        require!(current_time >= self.discovery_start_time, HypeBondError::TradingNotStarted);
         Ok(())
     }
 
+    // Whether the curve currently accepts buys and sells
+    pub fn check_tradable(&self, current_time: i64) -> Result<()> {
+        self.check_started(current_time)?;
+        require!(
+            self.curve_state != CurveState::PriceDiscovery
+                || self.discovery_mode == DiscoveryMode::Continuous,
+            HypeBondError::WrongDiscoveryMode
+        );
+        require!(self.curve_state != CurveState::Failed, HypeBondError::CurveFailed);
+        require!(!self.complete, HypeBondError::BondingCurveComplete);
+        Ok(())
+    }
+
+    // Whether buys are still restricted to allowlisted wallets
+    pub fn in_allowlist_window(&self, current_time: i64) -> bool {
+        self.curve_state == CurveState::PriceDiscovery
+            && self.allowlist_duration > 0
+            && current_time < self.discovery_start_time.saturating_add(self.allowlist_duration)
+    }
+
+    // Enforce the wallet limit for the current phase after a buy
+    pub fn check_wallet_limits(&self, discovery_tokens_bought: u64, wallet_balance: u64) -> Result<()> {
+        match self.curve_state {
+            CurveState::PriceDiscovery => require!(
+                self.discovery_wallet_cap == 0 || discovery_tokens_bought <= self.discovery_wallet_cap,
+                HypeBondError::WalletCapExceeded
+            ),
+            CurveState::BondingCurve => require!(
+                self.max_wallet_tokens == 0 || wallet_balance <= self.max_wallet_tokens,
+                HypeBondError::MaxWalletExceeded
+            ),
+            CurveState::Failed => return err!(HypeBondError::CurveFailed),
+        }
+        Ok(())
+    }
+
+    pub fn check_trade_size(&self, amount: u64) -> Result<()> {
+        require!(
+            amount >= self.min_trade_amount && amount <= self.max_trade_amount,
+            HypeBondError::InvalidTradeSize
+        );
+        Ok(())
+    }
+
+    // Record a discovery trade for clearing price derivation
+    fn record_discovery_trade(&mut self, token_amount: u64, sol_amount: u64, is_buy: bool) -> Result<()> {
+        if token_amount == 0 {
+            return Ok(());
+        }
+        if is_buy {
+            self.discovery_sol_volume = self.discovery_sol_volume
+                .checked_add(sol_amount)
+                .ok_or(HypeBondError::MathOverflow)?;
+            self.discovery_token_volume = self.discovery_token_volume
+                .checked_add(token_amount)
+                .ok_or(HypeBondError::MathOverflow)?;
+        }
+        self.last_trade_price = math::price_of(sol_amount, token_amount)?;
+        Ok(())
+    }
+
+    // Policy in force for this curve: its override or the global default
+    pub fn effective_policy(&self, global_policy: &ProtectionPolicy) -> ProtectionPolicy {
+        self.protection_override.unwrap_or(*global_policy)
+    }
+
+    // Dynamic safety band based on trailing volume, from 1x (100) up to the
+    // policy maximum
+    fn dynamic_multiplier(&self, policy: &ProtectionPolicy, current_time: i64) -> Result<u64> {
+        let (buy_volume, sell_volume) = self.trailing_volume(current_time);
+        let volume_factor = buy_volume.saturating_add(sell_volume) / policy.volume_threshold;
+        let multiplier = volume_factor
+            .checked_mul(self.volume_multiplier)
+            .and_then(|extra| extra.checked_add(self.base_slippage))
+            .ok_or(HypeBondError::MathOverflow)?;
+        Ok(multiplier.clamp(100, policy.max_slippage.max(100)))
+    }
+
     // Apply protection multipliers to a base amount
-    pub fn apply_protections(&self, base_amount: u64, amount: u64) -> Result<u64> {
+    pub fn apply_protections(
+        &self,
+        base_amount: u64,
+        amount: u64,
+        policy: &ProtectionPolicy,
+        current_time: i64,
+    ) -> Result<u64> {
         // Apply progressive protection based on size
-        let protection_multiplier = if amount < SMALL_TRADE_THRESHOLD {
-            101 // 1% slippage
-        } else if amount < MEDIUM_TRADE_THRESHOLD {
-            103 // 3% slippage
-        } else {
-            105 // 5% slippage
-        };
+        let protection_multiplier = policy.buy_multiplier(amount);
 
-        // Apply dynamic safety bands based on volume
-        let volume_factor = self.last_hour_volume
-            .checked_div(VOLUME_THRESHOLD)
-            .unwrap_or(1);
-        
-        let dynamic_multiplier = self.base_slippage
-            .checked_add(volume_factor * self.volume_multiplier)
-            .unwrap_or(MAX_SLIPPAGE);
+        // Calculate final amount with all protections, rounded up for the buyer
+        to_u64(mul_div_u128(
+            base_amount as u128 * protection_multiplier as u128,
+            self.dynamic_multiplier(policy, current_time)? as u128,
+            10000,
+            Rounding::Up,
+        )?)
+    }
+
+    // Apply the stronger sell-side protections to a base amount
+    pub fn apply_sell_protections(
+        &self,
+        base_amount: u64,
+        amount: u64,
+        policy: &ProtectionPolicy,
+        current_time: i64,
+    ) -> Result<u64> {
+        let protection_multiplier = policy.sell_multiplier(amount);
 
-        // Calculate final amount with all protections
-        base_amount
-            .checked_mul(protection_multiplier)
-            .unwrap_or(base_amount)
-            .checked_mul(dynamic_multiplier)
-            .unwrap_or(base_amount)
-            .checked_div(10000)
-            .ok_or(HypeBondError::MathOverflow.into())
+        // Volume widens the spread against the seller: divide by the dynamic
+        // multiplier so the payout never exceeds the constant product value.
+        // Rounded down for the seller
+        to_u64(mul_div_u128(
+            base_amount as u128,
+            protection_multiplier as u128,
+            self.dynamic_multiplier(policy, current_time)? as u128,
+            Rounding::Down,
+        )?)
     }
 
     // Calculate buy price with all protections - now phase-aware
-    pub fn calculate_buy_price(&self, amount: u64, current_time: i64) -> Result<u64> {
+    pub fn calculate_buy_price(&self, amount: u64, current_time: i64, policy: &ProtectionPolicy) -> Result<u64> {
         match self.curve_state {
             CurveState::PriceDiscovery => {
                 // In discovery phase, price decreases over time
                 let token_price = self.get_discovery_price(current_time)?;
                 
                 // Total cost for amount of tokens at this price
-                let base_sol_cost = amount
-                    .checked_mul(token_price)
-                    .unwrap_or(0)
-                    .checked_div(1_000_000_000) // Convert from lamports
-                    .unwrap_or(0);
+                let base_sol_cost = math::tokens_to_sol(amount, token_price, Rounding::Up)?;
                 
                 // Apply protections
-                self.apply_protections(base_sol_cost, amount)
+                self.apply_protections(base_sol_cost, amount, policy, current_time)
             },
             CurveState::BondingCurve => {
                 // In bonding curve phase, use constant product formula
                 let base_amount = self.get_base_price(amount, true)?;
-                self.apply_protections(base_amount, amount)
-            }
+                self.apply_protections(base_amount, amount, policy, current_time)
+            },
+            CurveState::Failed => err!(HypeBondError::CurveFailed)
         }
     }
 
     // Protective sell price calculation - now phase-aware
-    pub fn calculate_sell_price(&self, amount: u64, current_time: i64) -> Result<u64> {
+    pub fn calculate_sell_price(&self, amount: u64, current_time: i64, policy: &ProtectionPolicy) -> Result<u64> {
         match self.curve_state {
             CurveState::PriceDiscovery => {
                 // In discovery phase, sell at current price with penalty
                 let token_price = self.get_discovery_price(current_time)?;
                 
-                // Apply a fixed 5% penalty on discovery phase sells
-                let base_sol_receive = amount
-                    .checked_mul(token_price)
-                    .unwrap_or(0)
-                    .checked_mul(95) // 5% penalty
-                    .unwrap_or(0)
-                    .checked_div(100)
-                    .unwrap_or(0)
-                    .checked_div(1_000_000_000) // Convert from lamports
-                    .unwrap_or(0);
-                
-                // Apply standard protections
-                Ok(base_sol_receive)
+                // Apply the flat discovery phase sell penalty
+                let base_sol_receive = math::tokens_to_sol(amount, token_price, Rounding::Down)?;
+                math::apply_bps(base_sol_receive, policy.discovery_sell_bps, Rounding::Down)
             },
             CurveState::BondingCurve => {
                 // In bonding curve phase, use constant product formula
                 let base_amount = self.get_base_price(amount, false)?;
-                
-                // Apply stronger protection for sells
-                let protection_multiplier = if amount < SMALL_TRADE_THRESHOLD {
-                    99  // 1% penalty
-                } else if amount < MEDIUM_TRADE_THRESHOLD {
-                    97  // 3% penalty
+                self.apply_sell_protections(base_amount, amount, policy, current_time)
+            },
+            CurveState::Failed => err!(HypeBondError::CurveFailed)
+        }
+    }
+
+    // Largest token amount whose cost plus fee fits within sol_budget. Buy
+    // pricing is piecewise across protection tiers, so the forward formula is
+    // searched instead of inverted and every candidate is priced exactly as
+    // buy would price it.
+    pub fn buy_amount_for_sol(
+        &self,
+        sol_budget: u64,
+        fee_basis_points: u64,
+        current_time: i64,
+        policy: &ProtectionPolicy,
+    ) -> u64 {
+        let fits = |amount: u64| -> bool {
+            self.calculate_buy_price(amount, current_time, policy)
+                .and_then(|cost| {
+                    let fee = math::apply_bps(cost, fee_basis_points, Rounding::Up)?;
+                    Ok(cost.checked_add(fee).is_some_and(|total| total <= sol_budget))
+                })
+                .unwrap_or(false)
+        };
+
+        let (mut low, mut high) = (0u64, self.real_token_reserves);
+        while low < high {
+            let mid = low + (high - low).div_ceil(2);
+            if fits(mid) {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        low
+    }
+
+    // Smallest token amount whose output net of the fee reaches sol_target.
+    // Larger sells pay a lower tier multiplier, so output only grows within a
+    // tier band: search each band in turn and take the first that reaches
+    pub fn sell_amount_for_sol(
+        &self,
+        sol_target: u64,
+        fee_basis_points: u64,
+        current_time: i64,
+        policy: &ProtectionPolicy,
+    ) -> Result<u64> {
+        let reaches = |amount: u64| -> bool {
+            self.check_trade_size(amount).is_ok()
+                && self.calculate_sell_price(amount, current_time, policy)
+                    .and_then(|output| {
+                        let fee = math::apply_bps(output, fee_basis_points, Rounding::Up)?;
+                        Ok(output.checked_sub(fee).is_some_and(|net| net >= sol_target))
+                    })
+                    .unwrap_or(false)
+        };
+
+        let bands = [
+            (0, policy.small_trade_threshold.saturating_sub(1)),
+            (policy.small_trade_threshold, policy.medium_trade_threshold.saturating_sub(1)),
+            (policy.medium_trade_threshold, u64::MAX),
+        ];
+        for (band_low, band_high) in bands {
+            let mut low = band_low.max(self.min_trade_amount);
+            let mut high = band_high.min(self.max_trade_amount);
+            if low > high || !reaches(high) {
+                continue;
+            }
+            while low < high {
+                let mid = low + (high - low) / 2;
+                if reaches(mid) {
+                    high = mid;
                 } else {
-                    95  // 5% penalty
-                };
-                
-                // Dynamic slippage based on recent volume
-                let volume_factor = self.last_hour_volume
-                    .checked_div(VOLUME_THRESHOLD)
-                    .unwrap_or(1);
-                
-                let dynamic_multiplier = self.base_slippage
-                    .checked_add(volume_factor * self.volume_multiplier)
-                    .unwrap_or(MAX_SLIPPAGE);
-                
-                // Calculate final price with all protections
-                base_amount
-                    .checked_mul(protection_multiplier)
-                    .unwrap_or(base_amount)
-                    .checked_mul(dynamic_multiplier)
-                    .unwrap_or(base_amount)
-                    .checked_div(10000)
-                    .ok_or(HypeBondError::MathOverflow.into())
+                    low = mid + 1;
+                }
             }
+            return Ok(high);
         }
+
+        err!(HypeBondError::TooLittleSolReceived)
     }
 
     // Update reserves after trade
@@ -249,7 +676,11 @@ impl BondingCurve {
     ) -> Result<()> {
         // In discovery phase, we only update real reserves
         if self.curve_state == CurveState::PriceDiscovery {
+            self.record_discovery_trade(token_amount, sol_amount, is_buy)?;
+
             if is_buy {
+                self.epoch_tokens_sold = self.epoch_tokens_sold.saturating_add(token_amount);
+
                 self.real_token_reserves = self.real_token_reserves
                     .checked_sub(token_amount)
                     .ok_or(HypeBondError::MathOverflow)?;
@@ -258,6 +689,8 @@ impl BondingCurve {
                     .checked_add(sol_amount)
                     .ok_or(HypeBondError::MathOverflow)?;
             } else {
+                self.epoch_tokens_sold = self.epoch_tokens_sold.saturating_sub(token_amount);
+
                 self.real_token_reserves = self.real_token_reserves
                     .checked_add(token_amount)
                     .ok_or(HypeBondError::MathOverflow)?;
@@ -272,11 +705,11 @@ impl BondingCurve {
         // In bonding curve phase, update both virtual and real reserves
         if is_buy {
             self.virtual_token_reserves = self.virtual_token_reserves
-                .checked_add(token_amount)
+                .checked_sub(token_amount)
                 .ok_or(HypeBondError::MathOverflow)?;
             
             self.virtual_sol_reserves = self.virtual_sol_reserves
-                .checked_sub(sol_amount)
+                .checked_add(sol_amount)
                 .ok_or(HypeBondError::MathOverflow)?;
             
             self.real_token_reserves = self.real_token_reserves
@@ -288,11 +721,11 @@ impl BondingCurve {
                 .ok_or(HypeBondError::MathOverflow)?;
         } else {
             self.virtual_token_reserves = self.virtual_token_reserves
-                .checked_sub(token_amount)
+                .checked_add(token_amount)
                 .ok_or(HypeBondError::MathOverflow)?;
             
             self.virtual_sol_reserves = self.virtual_sol_reserves
-                .checked_add(sol_amount)
+                .checked_sub(sol_amount)
                 .ok_or(HypeBondError::MathOverflow)?;
             
             self.real_token_reserves = self.real_token_reserves
@@ -306,16 +739,70 @@ impl BondingCurve {
         Ok(())
     }
 
-    pub fn update_volume(&mut self, amount: u64, timestamp: i64) {
-        if timestamp - self.last_hour_timestamp > 3600 {
-            self.last_hour_volume = amount;
-            self.last_hour_timestamp = timestamp;
+    pub fn tokens_sold(&self) -> u64 {
+        self.token_total_supply.saturating_sub(self.real_token_reserves)
+    }
+
+    // Tokens and lamports that seed the pool at the curve's closing spot
+    // price (virtual SOL / virtual tokens), using as much of sol_available
+    // and the real tokens as that price allows. Rounding only ever nudges the
+    // pool price up, by less than one token's worth.
+    pub fn graduation_liquidity(&self, sol_available: u64) -> Result<(u64, u64)> {
+        let tokens = mul_div_u128(
+            sol_available as u128,
+            self.virtual_token_reserves as u128,
+            self.virtual_sol_reserves as u128,
+            Rounding::Down,
+        )?;
+        if tokens <= self.real_token_reserves as u128 {
+            return Ok((to_u64(tokens)?, sol_available));
+        }
+
+        let sol = mul_div(
+            self.real_token_reserves,
+            self.virtual_sol_reserves,
+            self.virtual_token_reserves,
+            Rounding::Up,
+        )?;
+        Ok((self.real_token_reserves, sol.min(sol_available)))
+    }
+
+    // Whether the curve has hit its graduation threshold
+    pub fn is_completion_reached(&self) -> bool {
+        match self.completion_mode {
+            CompletionMode::TokensSold => self.tokens_sold() >= self.completion_threshold,
+            CompletionMode::SolRaised => self.real_sol_reserves >= self.completion_threshold,
+        }
+    }
+
+    // Add a trade to the bucket for its interval, recycling stale buckets
+    pub fn update_volume(&mut self, amount: u64, timestamp: i64, is_buy: bool) {
+        let interval = timestamp.div_euclid(VOLUME_BUCKET_DURATION);
+        let bucket = &mut self.volume_buckets[interval.rem_euclid(VOLUME_BUCKET_COUNT as i64) as usize];
+
+        if bucket.interval != interval {
+            *bucket = VolumeBucket { interval, ..Default::default() };
+        }
+
+        if is_buy {
+            bucket.buy_volume = bucket.buy_volume.saturating_add(amount);
         } else {
-            self.last_hour_volume = self.last_hour_volume
-                .checked_add(amount)
-                .unwrap_or(u64::MAX);
+            bucket.sell_volume = bucket.sell_volume.saturating_add(amount);
         }
     }
+
+    // Buy and sell volume over the trailing window ending at current_time
+    pub fn trailing_volume(&self, current_time: i64) -> (u64, u64) {
+        let current_interval = current_time.div_euclid(VOLUME_BUCKET_DURATION);
+        let oldest_interval = current_interval - VOLUME_BUCKET_COUNT as i64 + 1;
+
+        self.volume_buckets
+            .iter()
+            .filter(|bucket| bucket.interval >= oldest_interval && bucket.interval <= current_interval)
+            .fold((0u64, 0u64), |(buys, sells), bucket| {
+                (buys.saturating_add(bucket.buy_volume), sells.saturating_add(bucket.sell_volume))
+            })
+    }
     
     // Initialize curve with discovery phase parameters
     pub fn initialize_discovery(
@@ -324,16 +811,223 @@ impl BondingCurve {
         duration: i64,
         start_price: u64,
         end_price: u64,
+        decay_mode: DecayMode,
         total_supply: u64,
     ) -> Result<()> {
         self.curve_state = CurveState::PriceDiscovery;
+        self.token_total_supply = total_supply;
+        self.real_token_reserves = total_supply;
+        self.schedule_discovery(start_time, duration, start_price, end_price, decay_mode)
+    }
+
+    // Set the discovery window and price path; reserves are left untouched.
+    // The window must fit the rebalance epoch and allowlist set at creation
+    pub fn schedule_discovery(
+        &mut self,
+        start_time: i64,
+        duration: i64,
+        start_price: u64,
+        end_price: u64,
+        decay_mode: DecayMode,
+    ) -> Result<()> {
+        require!(
+            duration > 0
+                && end_price > 0
+                && start_price >= end_price
+                && self.rebalance_epoch_duration <= duration
+                && self.allowlist_duration < duration,
+            HypeBondError::InvalidDiscoveryParams
+        );
+        require!(
+            decay_mode.is_valid(duration, start_price, end_price),
+            HypeBondError::InvalidDiscoveryParams
+        );
+
         self.discovery_start_time = start_time;
         self.discovery_duration = duration;
         self.discovery_start_price = start_price;
         self.discovery_end_price = end_price;
-        self.token_total_supply = total_supply;
-        self.real_token_reserves = total_supply;
+        self.decay_mode = decay_mode;
+
+        // Restart rebalancing from the unshifted path
+        self.current_epoch = 0;
+        self.epoch_tokens_sold = 0;
+        self.price_offset = 0;
+        self.discovery_sol_volume = 0;
+        self.discovery_token_volume = 0;
+        self.last_trade_price = 0;
         
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::VOLUME_THRESHOLD;
+
+    const START: i64 = 1_000;
+    const DURATION: i64 = 1_000;
+
+    fn discovery_curve(decay_mode: DecayMode) -> BondingCurve {
+        BondingCurve {
+            discovery_start_time: START,
+            discovery_duration: DURATION,
+            discovery_start_price: 2_000,
+            discovery_end_price: 1_000,
+            decay_mode,
+            ..Default::default()
+        }
+    }
+
+    fn bonding_curve(price: u64) -> BondingCurve {
+        let mut curve = BondingCurve {
+            curve_state: CurveState::BondingCurve,
+            token_total_supply: 800_000_000_000_000,
+            real_token_reserves: 800_000_000_000_000,
+            min_trade_amount: 1,
+            max_trade_amount: 1_000_000,
+            base_slippage: 100,
+            ..Default::default()
+        };
+        curve.seed_virtual_reserves(price).unwrap();
+        curve
+    }
+
+    #[test]
+    fn seed_virtual_reserves_hits_price_exactly() {
+        for price in [1, 7, 30_000, 123_456_789, PRICE_PRECISION, 3 * PRICE_PRECISION + 1] {
+            let curve = bonding_curve(price);
+            assert_eq!(curve.spot_price().unwrap(), price);
+            assert!(curve.virtual_token_reserves >= curve.token_total_supply);
+        }
+    }
+
+    #[test]
+    fn base_price_rounds_against_the_trader() {
+        let curve = bonding_curve(30_000);
+        let k = curve.virtual_token_reserves as u128 * curve.virtual_sol_reserves as u128;
+
+        for amount in [1, 999, 1_000_000, 10_000_000_000] {
+            let cost = curve.get_base_price(amount, true).unwrap();
+            let proceeds = curve.get_base_price(amount, false).unwrap();
+            assert!(cost >= proceeds);
+
+            let after_buy = (curve.virtual_token_reserves - amount) as u128
+                * (curve.virtual_sol_reserves + cost) as u128;
+            let after_sell = (curve.virtual_token_reserves + amount) as u128
+                * (curve.virtual_sol_reserves - proceeds) as u128;
+            assert!(after_buy >= k);
+            assert!(after_sell >= k);
+        }
+    }
+
+    #[test]
+    fn base_price_rejects_draining_the_curve() {
+        let curve = bonding_curve(30_000);
+        assert!(curve.get_base_price(curve.virtual_token_reserves, true).is_err());
+    }
+
+    #[test]
+    fn linear_decay_interpolates() {
+        let curve = discovery_curve(DecayMode::Linear);
+        assert_eq!(curve.get_discovery_price(START - 10).unwrap(), 2_000);
+        assert_eq!(curve.get_discovery_price(START + 250).unwrap(), 1_750);
+        assert_eq!(curve.get_discovery_price(START + DURATION + 10).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn exponential_decay_halves_per_half_life() {
+        let curve = discovery_curve(DecayMode::Exponential { half_life: 100 });
+        assert_eq!(curve.get_discovery_price(START).unwrap(), 2_000);
+        assert_eq!(curve.get_discovery_price(START + 100).unwrap(), 1_500);
+        assert_eq!(curve.get_discovery_price(START + 150).unwrap(), 1_375);
+        assert_eq!(curve.get_discovery_price(START + 200).unwrap(), 1_250);
+        assert_eq!(curve.get_discovery_price(START + DURATION).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn stepwise_decay_holds_between_steps() {
+        let curve = discovery_curve(DecayMode::Stepwise { step_duration: 250 });
+        assert_eq!(curve.get_discovery_price(START + 249).unwrap(), 2_000);
+        assert_eq!(curve.get_discovery_price(START + 250).unwrap(), 1_750);
+        assert_eq!(curve.get_discovery_price(START + 999).unwrap(), 1_250);
+        assert_eq!(curve.get_discovery_price(START + DURATION).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn piecewise_decay_follows_breakpoints() {
+        let mut breakpoints = [PriceBreakpoint::default(); MAX_PRICE_BREAKPOINTS];
+        breakpoints[0] = PriceBreakpoint { time_offset: 100, price: 1_200 };
+        let mode = DecayMode::Piecewise { breakpoints, count: 1 };
+        assert!(mode.is_valid(DURATION, 2_000, 1_000));
+
+        let curve = discovery_curve(mode);
+        assert_eq!(curve.get_discovery_price(START + 50).unwrap(), 1_600);
+        assert_eq!(curve.get_discovery_price(START + 100).unwrap(), 1_200);
+        assert_eq!(curve.get_discovery_price(START + 550).unwrap(), 1_100);
+        assert_eq!(curve.get_discovery_price(START + DURATION).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn piecewise_rejects_rising_breakpoints() {
+        let mut breakpoints = [PriceBreakpoint::default(); MAX_PRICE_BREAKPOINTS];
+        breakpoints[0] = PriceBreakpoint { time_offset: 100, price: 1_500 };
+        breakpoints[1] = PriceBreakpoint { time_offset: 200, price: 1_600 };
+        assert!(!DecayMode::Piecewise { breakpoints, count: 2 }.is_valid(DURATION, 2_000, 1_000));
+    }
+
+    #[test]
+    fn discovery_end_time_saturates() {
+        let mut curve = discovery_curve(DecayMode::Linear);
+        curve.discovery_start_time = i64::MAX - 10;
+        assert_eq!(curve.discovery_end_time(), i64::MAX);
+        assert!(!curve.check_and_update_state(0).unwrap());
+    }
+
+    #[test]
+    fn sell_amount_for_sol_is_smallest_across_tiers() {
+        let curve = bonding_curve(PRICE_PRECISION);
+        let policy = ProtectionPolicy {
+            small_trade_threshold: 100,
+            medium_trade_threshold: 200,
+            ..Default::default()
+        };
+        let net = |amount: u64| {
+            let output = curve.calculate_sell_price(amount, 0, &policy).unwrap();
+            output - math::apply_bps(output, 100, Rounding::Up).unwrap()
+        };
+
+        // 99 tokens at 99% pay more than 100 tokens at 97%
+        let target = net(99);
+        assert!(net(100) < target);
+        for target in [1, target, net(150), net(250)] {
+            let amount = curve.sell_amount_for_sol(target, 100, 0, &policy).unwrap();
+            let smallest = (1..=300).find(|amount| net(*amount) >= target).unwrap();
+            assert_eq!(amount, smallest);
+        }
+    }
+
+    #[test]
+    fn sell_protections_never_pay_above_constant_product() {
+        let mut curve = bonding_curve(PRICE_PRECISION);
+        curve.volume_multiplier = 10;
+        curve.update_volume(10 * VOLUME_THRESHOLD, 0, true);
+
+        let policy = ProtectionPolicy::default();
+        let base = curve.get_base_price(1_000, false).unwrap();
+        assert!(curve.calculate_sell_price(1_000, 0, &policy).unwrap() <= base);
+    }
+
+    #[test]
+    fn schedule_discovery_enforces_create_time_checks() {
+        let mut curve = discovery_curve(DecayMode::Linear);
+        curve.rebalance_epoch_duration = 500;
+
+        assert!(curve.schedule_discovery(START, DURATION, 2_000, 0, DecayMode::Linear).is_err());
+        assert!(curve.schedule_discovery(START, DURATION, 1_000, 2_000, DecayMode::Linear).is_err());
+        assert!(curve.schedule_discovery(START, 400, 2_000, 1_000, DecayMode::Linear).is_err());
+        assert!(curve.schedule_discovery(START, 0, 2_000, 1_000, DecayMode::Linear).is_err());
+        assert!(curve.schedule_discovery(START, 500, 2_000, 1_000, DecayMode::Linear).is_ok());
+    }
+}
@@ -31,4 +31,142 @@ pub enum HypeBondError {
 
     #[msg("Invalid milestone count")]
     InvalidMilestoneCount,
-}
\ No newline at end of file
+
+    #[msg("Invalid discovery parameters")]
+    InvalidDiscoveryParams,
+
+    #[msg("SOL vault balance too low")]
+    InsufficientVaultBalance,
+
+    #[msg("Invalid completion threshold")]
+    InvalidCompletionThreshold,
+
+    #[msg("Bonding curve not complete")]
+    BondingCurveNotComplete,
+
+    #[msg("Insufficient pool liquidity")]
+    InsufficientLiquidity,
+
+    #[msg("Slippage tolerance exceeded")]
+    SlippageExceeded,
+
+    #[msg("Instruction not available in this discovery mode")]
+    WrongDiscoveryMode,
+
+    #[msg("Discovery window has ended")]
+    DiscoveryEnded,
+
+    #[msg("Discovery window has not ended")]
+    DiscoveryNotEnded,
+
+    #[msg("Invalid bid price level")]
+    InvalidBidLevel,
+
+    #[msg("Auction already settled")]
+    AuctionAlreadySettled,
+
+    #[msg("Auction not settled")]
+    AuctionNotSettled,
+
+    #[msg("Launch failed to meet its reserve")]
+    CurveFailed,
+
+    #[msg("Launch has not failed")]
+    CurveNotFailed,
+
+    #[msg("Invalid protection policy")]
+    InvalidProtectionPolicy,
+
+    #[msg("Transaction landed after its deadline")]
+    DeadlineExceeded,
+
+    #[msg("Price moved beyond the allowed impact")]
+    PriceImpactExceeded,
+
+    #[msg("Purchase exceeds the per-wallet discovery cap")]
+    WalletCapExceeded,
+
+    #[msg("Purchase exceeds the maximum wallet holdings")]
+    MaxWalletExceeded,
+
+    #[msg("Wallet is not on the allowlist")]
+    NotAllowlisted,
+
+    #[msg("Purchase exceeds the wallet's allowlist allocation")]
+    AllowlistAllocationExceeded,
+
+    #[msg("Trading has not started yet")]
+    TradingNotStarted,
+
+    #[msg("Invalid escrow share")]
+    InvalidEscrowShare,
+
+    #[msg("Invalid milestone deadline")]
+    InvalidMilestoneDeadline,
+
+    #[msg("Milestone deadline has passed")]
+    MilestoneDeadlinePassed,
+
+    #[msg("Milestone is not overdue")]
+    MilestoneNotOverdue,
+
+    #[msg("Project has been abandoned")]
+    ProjectAbandoned,
+
+    #[msg("Project has not been abandoned")]
+    ProjectNotAbandoned,
+
+    #[msg("Invalid vesting config")]
+    InvalidVestingConfig,
+
+    #[msg("Streamflow accounts are required to vest team tokens")]
+    MissingStreamAccounts,
+
+    #[msg("Vesting schedule account is required to vest team tokens")]
+    MissingVestingSchedule,
+
+    #[msg("Nothing to claim")]
+    NothingToClaim,
+
+    #[msg("Team shares must be unique wallets summing to 100%")]
+    InvalidTeamAllocation,
+
+    #[msg("Wallet is not a team member")]
+    NotTeamMember,
+
+    #[msg("Milestone not completed")]
+    MilestoneNotCompleted,
+
+    #[msg("Invalid governance config")]
+    InvalidGovernanceConfig,
+
+    #[msg("A completion proposal is still being voted on")]
+    ProposalActive,
+
+    #[msg("Vote amount must be positive")]
+    InvalidVoteAmount,
+
+    #[msg("Voting is closed")]
+    VotingClosed,
+
+    #[msg("Voting has not ended")]
+    VotingNotEnded,
+
+    #[msg("Completion proposal did not reach quorum and approval")]
+    ProposalNotPassed,
+
+    #[msg("Holders approved this milestone")]
+    ProposalPassed,
+
+    #[msg("Completion proposal is required")]
+    MissingProposal,
+
+    #[msg("Discovery has already started")]
+    DiscoveryAlreadyStarted,
+
+    #[msg("Bonding curve token account is required")]
+    MissingCurveTokens,
+
+    #[msg("Every milestone must be completed first")]
+    MilestonesIncomplete,
+}
@@ -0,0 +1,312 @@
+use anchor_lang::prelude::*;
+use crate::curve::{BondingCurve, CurveState, DiscoveryMode};
+use crate::contexts::ProtectionPolicy;
+
+// Typed events emitted through self-CPI so indexers can decode them from
+// instruction data instead of parsing program logs.
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LaunchPhase {
+    PriceDiscovery,
+    BondingCurve,
+    Failed,
+    Complete,     // Threshold reached, waiting to graduate
+    Graduated,    // Liquidity migrated to the pool
+}
+
+impl From<&CurveState> for LaunchPhase {
+    fn from(state: &CurveState) -> Self {
+        match state {
+            CurveState::PriceDiscovery => LaunchPhase::PriceDiscovery,
+            CurveState::BondingCurve => LaunchPhase::BondingCurve,
+            CurveState::Failed => LaunchPhase::Failed,
+        }
+    }
+}
+
+#[event]
+pub struct TradeEvent {
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub user: Pubkey,
+    pub is_buy: bool,
+    pub phase: LaunchPhase,
+    pub token_amount: u64,
+    pub sol_amount: u64,            // Lamports paid or received before fees
+    pub fee_amount: u64,
+    pub price: u64,                 // Average execution price, lamports per PRICE_PRECISION tokens
+    pub virtual_token_reserves: u64,
+    pub virtual_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub timestamp: i64,
+}
+
+// Constant product trade on a graduated pool
+#[event]
+pub struct PoolSwapEvent {
+    pub mint: Pubkey,
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub is_buy: bool,               // SOL in, tokens out
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee_amount: u64,            // Taken from amount_in and left in the pool
+    pub price: u64,                 // Average execution price, lamports per PRICE_PRECISION tokens
+    pub token_reserves: u64,
+    pub sol_reserves: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LiquidityEvent {
+    pub mint: Pubkey,
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub is_deposit: bool,
+    pub lp_amount: u64,             // LP minted or burned
+    pub token_amount: u64,
+    pub sol_amount: u64,
+    pub lp_supply: u64,
+    pub token_reserves: u64,
+    pub sol_reserves: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BondingCurveCreatedEvent {
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub project: Pubkey,
+    pub authority: Pubkey,
+    pub market_tokens: u64,         // Moved from the project vault to the curve
+    pub discovery_mode: DiscoveryMode,
+    pub discovery_start_time: i64,
+    pub discovery_duration: i64,
+    pub discovery_start_price: u64,
+    pub discovery_end_price: u64,
+    pub escrow_share_bps: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BidPlacedEvent {
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub bidder: Pubkey,
+    pub level: u8,
+    pub price: u64,                 // Level price, lamports per PRICE_PRECISION tokens
+    pub sol_amount: u64,
+    pub bid_sol: u64,               // Bidder's total at this level
+    pub total_bid_sol: u64,         // Whole book
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BidClaimedEvent {
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub bidder: Pubkey,
+    pub level: u8,
+    pub tokens_filled: u64,
+    pub sol_cost: u64,
+    pub sol_refunded: u64,
+    pub timestamp: i64,
+}
+
+// Tokens handed back to a failed curve for a share of the SOL raised
+#[event]
+pub struct RefundClaimedEvent {
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub user: Pubkey,
+    pub tokens_returned: u64,
+    pub sol_refunded: u64,
+    pub real_sol_reserves: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProtectionPolicyUpdatedEvent {
+    pub authority: Pubkey,
+    pub bonding_curve: Option<Pubkey>,  // Set for per-curve overrides
+    pub policy: Option<ProtectionPolicy>,  // None clears a curve override
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PhaseTransitionEvent {
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub from_phase: LaunchPhase,
+    pub to_phase: LaunchPhase,
+    pub clearing_price: u64,
+    pub virtual_token_reserves: u64,
+    pub virtual_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub timestamp: i64,
+}
+
+impl PhaseTransitionEvent {
+    // Snapshot the curve right after it moved between phases
+    pub fn new(
+        mint: Pubkey,
+        curve: &Account<BondingCurve>,
+        from_phase: LaunchPhase,
+        to_phase: LaunchPhase,
+        timestamp: i64,
+    ) -> Self {
+        Self {
+            mint,
+            bonding_curve: curve.key(),
+            from_phase,
+            to_phase,
+            clearing_price: curve.clearing_price,
+            virtual_token_reserves: curve.virtual_token_reserves,
+            virtual_sol_reserves: curve.virtual_sol_reserves,
+            real_token_reserves: curve.real_token_reserves,
+            real_sol_reserves: curve.real_sol_reserves,
+            timestamp,
+        }
+    }
+}
+
+#[event]
+pub struct ProjectCreatedEvent {
+    pub project: Pubkey,
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub name: String,
+    pub ticker: String,
+    pub total_supply: u64,
+    pub total_milestones: u8,
+    pub project_percentage: u8,
+    pub unlock_per_milestone: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MilestoneCompletedEvent {
+    pub project: Pubkey,
+    pub milestone: Pubkey,
+    pub milestone_id: u8,
+    pub completed_milestones: u8,
+    pub total_milestones: u8,
+    pub team_tokens: u64,
+    pub treasury_tokens: u64,
+    pub tokens_unlocked: u64,       // Cumulative tokens released from the vault
+    pub sol_released: u64,          // Escrowed lamports earmarked for the team
+    pub proposal: Pubkey,           // Holder vote that approved the completion
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteWithdrawnEvent {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub amount: u64,                // Deposited tokens returned to the voter
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalCreatedEvent {
+    pub project: Pubkey,
+    pub milestone: Pubkey,
+    pub milestone_id: u8,
+    pub proposal: Pubkey,
+    pub proposal_id: u8,
+    pub eligible_supply: u64,
+    pub quorum_bps: u16,
+    pub approval_bps: u16,
+    pub voting_start: i64,
+    pub voting_end: i64,
+}
+
+#[event]
+pub struct VoteCastEvent {
+    pub proposal: Pubkey,
+    pub milestone: Pubkey,
+    pub voter: Pubkey,
+    pub approve: bool,
+    pub amount: u64,                // Tokens deposited as voting weight
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TeamAllocationClaimedEvent {
+    pub project: Pubkey,
+    pub milestone: Pubkey,
+    pub milestone_id: u8,
+    pub member: Pubkey,
+    pub share_bps: u16,
+    pub tokens_claimed: u64,        // Paid directly or locked in the member's stream
+    pub stream: Pubkey,             // Streamflow stream vesting the tokens, default if paid directly
+    pub sol_claimed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EscrowReleasedEvent {
+    pub project: Pubkey,
+    pub milestone: Pubkey,          // Completed milestone the lamports were earmarked on
+    pub milestone_id: u8,
+    pub sol_released: u64,          // Deposited after the final milestone completed
+    pub team_sol: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VestedTokensClaimedEvent {
+    pub project: Pubkey,
+    pub milestone: Pubkey,
+    pub member: Pubkey,
+    pub amount: u64,
+    pub member_claimed: u64,        // Member's total from this schedule
+    pub schedule_claimed: u64,      // All members' total from this schedule
+    pub total_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProjectAbandonedEvent {
+    pub project: Pubkey,
+    pub milestone: Pubkey,          // Overdue milestone that triggered abandonment
+    pub milestone_id: u8,
+    pub deadline: i64,
+    pub tokens_burned: u64,         // Locked vault tokens destroyed
+    pub escrow_remaining: u64,      // Lamports left for holder redemption
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RedemptionEvent {
+    pub project: Pubkey,
+    pub holder: Pubkey,
+    pub tokens_burned: u64,
+    pub sol_redeemed: u64,
+    pub escrow_remaining: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParamsUpdatedEvent {
+    pub authority: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub initial_virtual_token_reserves: u64,
+    pub initial_virtual_sol_reserves: u64,
+    pub initial_real_token_reserves: u64,
+    pub token_total_supply: u64,
+    pub fee_basis_points: u64,
+    pub bonding_curve: Option<Pubkey>,  // Set when a curve's discovery was reset
+    pub discovery_start_time: i64,      // As requested (0 if unset) when no curve was passed
+    pub discovery_duration: i64,
+    pub discovery_start_price: u64,
+    pub discovery_end_price: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::errors::HypeBondError;
+use crate::math;
+
+// Point after which a signed trade must no longer execute
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Deadline {
+    UnixTimestamp(i64),
+    Slot(u64),
+}
+
+impl Deadline {
+    pub fn check(deadline: Option<Deadline>, clock: &Clock) -> Result<()> {
+        let expired = match deadline {
+            Some(Deadline::UnixTimestamp(timestamp)) => clock.unix_timestamp > timestamp,
+            Some(Deadline::Slot(slot)) => clock.slot > slot,
+            None => false,
+        };
+        require!(!expired, HypeBondError::DeadlineExceeded);
+        Ok(())
+    }
+}
+
+// Marginal price captured before the trade could move the curve (including
+// any phase transition it triggers), so the execution price can be bounded
+// relative to what the trader saw when signing
+#[derive(Clone, Copy)]
+pub struct PriceGuard {
+    pub reference_price: u64,
+    pub max_price_impact_bps: Option<u64>,
+}
+
+impl PriceGuard {
+    pub fn check(&self, sol_amount: u64, token_amount: u64, is_buy: bool) -> Result<()> {
+        if let Some(max_price_impact_bps) = self.max_price_impact_bps {
+            let execution_price = math::price_of(sol_amount, token_amount)?;
+            let impact = math::price_impact_bps(self.reference_price, execution_price, is_buy)?;
+            require!(impact <= max_price_impact_bps, HypeBondError::PriceImpactExceeded);
+        }
+        Ok(())
+    }
+}
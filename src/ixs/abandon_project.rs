@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+use crate::{contexts::*, errors::HypeBondError, events::ProjectAbandonedEvent};
+use super::propose_milestone::locked_balance;
+
+pub fn abandon_project(ctx: Context<AbandonProject>, milestone_id: u8) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    let milestone = &ctx.accounts.milestone;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // 1. Anyone can call this once a milestone misses its deadline
+    require!(!project.abandoned, HypeBondError::ProjectAbandoned);
+    require!(milestone.is_overdue(current_time), HypeBondError::MilestoneNotOverdue);
+
+    // A vote opened before the deadline gets to finish, and an approved
+    // milestone can still be completed
+    if milestone.proposal_count > 0 {
+        let proposal = ctx.accounts.last_proposal
+            .as_ref()
+            .ok_or(HypeBondError::MissingProposal)?;
+        require!(proposal.has_ended(current_time), HypeBondError::VotingNotEnded);
+        require!(!proposal.has_passed()?, HypeBondError::ProposalPassed);
+    }
+    project.abandoned = true;
+
+    // 2. Burn the tokens still locked in the vault. Team tokens earned on
+    // completed milestones stay behind for members to claim
+    let tokens_burned = ctx.accounts.vault.amount.saturating_sub(project.team_tokens_owed);
+    if tokens_burned > 0 {
+        let seeds = &[
+            b"project".as_ref(),
+            project.name.as_bytes(),
+            project.ticker.as_bytes(),
+            &[ctx.bumps.project]
+        ];
+        let signer = &[&seeds[..]];
+
+        token::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.vault.to_account_info(),
+                    authority: project.to_account_info(),
+                },
+                signer
+            ),
+            tokens_burned
+        )?;
+    }
+
+    // 3. Snapshot the supply that can redeem. Curve and pool tokens never
+    // can; vote deposits are withdrawable once voting ends, so they count
+    if let Some(escrow) = ctx.accounts.project_escrow.as_mut() {
+        let mint_key = ctx.accounts.mint.key();
+        require!(ctx.accounts.bonding_curve_tokens.is_some(), HypeBondError::MissingCurveTokens);
+        let curve_tokens = locked_balance(&ctx.accounts.bonding_curve_tokens, b"bonding-curve", &mint_key)?;
+        let pool_tokens = locked_balance(&ctx.accounts.pool_tokens, b"pool", &mint_key)?;
+        escrow.redeemable_supply = ctx.accounts.mint.supply
+            .checked_sub(tokens_burned)
+            .and_then(|supply| supply.checked_sub(curve_tokens))
+            .and_then(|supply| supply.checked_sub(pool_tokens))
+            .ok_or(HypeBondError::MathOverflow)?;
+    }
+
+    emit_cpi!(ProjectAbandonedEvent {
+        project: project.key(),
+        milestone: milestone.key(),
+        milestone_id,
+        deadline: milestone.deadline,
+        tokens_burned,
+        escrow_remaining: ctx.accounts.project_escrow
+            .as_ref()
+            .map_or(0, |escrow| escrow.remaining()),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(milestone_id: u8)]
+pub struct AbandonProject<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.name.as_bytes(), project.ticker.as_bytes()],
+        bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"token", project.name.as_bytes(), project.ticker.as_bytes()],
+        bump
+    )]
+    pub token_details: Account<'info, TokenDetails>,
+
+    #[account(
+        seeds = [b"milestone", project.key().as_ref(), &[milestone_id]],
+        bump,
+        constraint = milestone.project == project.key() @ HypeBondError::NotAuthorized
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    // Required once a completion proposal has been opened
+    #[account(address = milestone.last_proposal @ HypeBondError::MissingProposal)]
+    pub last_proposal: Option<Account<'info, MilestoneProposal>>,
+
+    #[account(
+        mut,
+        address = token_details.mint
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", project.name.as_bytes(), project.ticker.as_bytes()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    // Absent if the project never launched a curve
+    #[account(
+        mut,
+        seeds = [b"escrow", project.key().as_ref()],
+        bump
+    )]
+    pub project_escrow: Option<Account<'info, ProjectEscrow>>,
+
+    // Curve token account, required with the escrow; pool tokens once graduated
+    #[account(token::mint = mint)]
+    pub bonding_curve_tokens: Option<Account<'info, TokenAccount>>,
+
+    #[account(token::mint = mint)]
+    pub pool_tokens: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{self, Token, TokenAccount, Mint, MintTo},
+    associated_token::AssociatedToken,
+};
+use crate::{pool::Pool, errors::HypeBondError, contexts::SolVault, events::LiquidityEvent};
+
+pub fn add_liquidity(
+    ctx: Context<AddLiquidity>,
+    lp_amount: u64,
+    max_token_amount: u64,
+    max_sol_amount: u64,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let user = &ctx.accounts.user;
+
+    // 1. Deposit must match the current reserve ratio
+    let (token_amount, sol_amount) = pool.calculate_deposit(lp_amount)?;
+    require!(
+        token_amount <= max_token_amount && sol_amount <= max_sol_amount,
+        HypeBondError::SlippageExceeded
+    );
+
+    // 2. Transfer tokens: User -> Pool
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.associated_user.to_account_info(),
+                to: ctx.accounts.pool_token_account.to_account_info(),
+                authority: user.to_account_info(),
+            },
+        ),
+        token_amount
+    )?;
+
+    // 3. Transfer SOL: User -> Pool SOL Vault
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: user.to_account_info(),
+                to: ctx.accounts.pool_sol_vault.to_account_info(),
+            },
+        ),
+        sol_amount
+    )?;
+
+    // 4. Mint LP tokens to the user
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[
+        b"pool".as_ref(),
+        mint_key.as_ref(),
+        &[ctx.bumps.pool]
+    ];
+    let signer = &[&seeds[..]];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.lp_mint.to_account_info(),
+                to: ctx.accounts.user_lp_account.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            signer
+        ),
+        lp_amount
+    )?;
+
+    pool.deposit(lp_amount, token_amount, sol_amount)?;
+
+    emit_cpi!(LiquidityEvent {
+        mint: mint_key,
+        pool: pool.key(),
+        user: user.key(),
+        is_deposit: true,
+        lp_amount,
+        token_amount,
+        sol_amount,
+        lp_supply: pool.lp_supply,
+        token_reserves: pool.token_reserves,
+        sol_reserves: pool.sol_reserves,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"pool", mint.key().as_ref()],
+        bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = pool
+    )]
+    pub pool_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"sol-vault", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_sol_vault: Account<'info, SolVault>,
+
+    #[account(
+        mut,
+        seeds = [b"lp-mint", pool.key().as_ref()],
+        bump
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = user
+    )]
+    pub associated_user: Account<'info, TokenAccount>,
+
+    // User's LP token account, created on first deposit
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = lp_mint,
+        associated_token::authority = user
+    )]
+    pub user_lp_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
@@ -3,92 +3,200 @@ use anchor_spl::{
     token::{self, Token, TokenAccount, Mint},
     associated_token::AssociatedToken,
 };
-use crate::{curve::{BondingCurve, CurveState}, errors::HypeBondError, contexts::Global};
+use crate::{
+    curve::{BondingCurve, CurveState},
+    errors::HypeBondError,
+    math::{self, Rounding},
+    contexts::{Global, SolVault, UserPosition},
+    events::{LaunchPhase, PhaseTransitionEvent, TradeEvent},
+    guards::{Deadline, PriceGuard},
+    allowlist::AllowlistProof,
+};
+
+pub fn buy(
+    mut ctx: Context<Buy>,
+    amount: u64,
+    max_sol_cost: u64,
+    deadline: Option<Deadline>,
+    max_price_impact_bps: Option<u64>,
+    allowlist_proof: Option<AllowlistProof>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let guard = begin_buy(&mut ctx, &clock, deadline, max_price_impact_bps)?;
+
+    // 2. Calculate price using appropriate formula based on phase
+    let curve = &ctx.accounts.bonding_curve;
+    let policy = curve.effective_policy(&ctx.accounts.global.protection_policy);
+    let sol_required = curve.calculate_buy_price(amount, current_time, &policy)?;
+    require!(sol_required <= max_sol_cost, HypeBondError::TooMuchSolRequired);
+
+    execute_buy(&mut ctx, amount, sol_required, current_time, guard, allowlist_proof)
+}
+
+// 1. Apply any pending phase transition and check the curve accepts buys,
+// capturing the price the trader signed against before anything moves
+pub(crate) fn begin_buy(
+    ctx: &mut Context<Buy>,
+    clock: &Clock,
+    deadline: Option<Deadline>,
+    max_price_impact_bps: Option<u64>,
+) -> Result<PriceGuard> {
+    Deadline::check(deadline, clock)?;
+
+    let current_time = clock.unix_timestamp;
+    let curve = &mut ctx.accounts.bonding_curve;
+    let guard = PriceGuard {
+        reference_price: curve.marginal_price(current_time)?,
+        max_price_impact_bps,
+    };
+
+    if curve.check_and_update_state(current_time)? {
+        emit_cpi!(PhaseTransitionEvent::new(
+            ctx.accounts.mint.key(),
+            curve,
+            LaunchPhase::PriceDiscovery,
+            LaunchPhase::from(&curve.curve_state),
+            current_time,
+        ));
+    }
+
+    curve.check_tradable(current_time)?;
+    Ok(guard)
+}
+
+// Settle a priced buy: move SOL and tokens, then record it on the curve
+pub(crate) fn execute_buy(
+    ctx: &mut Context<Buy>,
+    amount: u64,
+    sol_required: u64,
+    current_time: i64,
+    guard: PriceGuard,
+    allowlist_proof: Option<AllowlistProof>,
+) -> Result<()> {
+    guard.check(sol_required, amount, true)?;
 
-pub fn buy(ctx: Context<Buy>, amount: u64, max_sol_cost: u64) -> Result<()> {
-    // 1. Access key accounts
     let curve = &mut ctx.accounts.bonding_curve;
     let user = &ctx.accounts.user;
     let global = &ctx.accounts.global;
-    let current_time = Clock::get()?.unix_timestamp;
-    
-    // Check and potentially update curve state based on time
-    curve.check_and_update_state(current_time)?;
-    
-    // 2. Calculate price using appropriate formula based on phase
-    let sol_required = curve.calculate_buy_price(amount, current_time)?;
-    require!(sol_required <= max_sol_cost, HypeBondError::TooMuchSolRequired);
+    let mint_key = ctx.accounts.mint.key();
+
+    // Track the position and enforce the wallet limit for this phase
+    let position = &mut ctx.accounts.user_position;
+    position.bonding_curve = curve.key();
+    position.user = user.key();
+    position.record_buy(amount, sol_required, curve.curve_state == CurveState::PriceDiscovery)?;
+    let wallet_balance = ctx.accounts.associated_user.amount
+        .checked_add(amount)
+        .ok_or(HypeBondError::MathOverflow)?;
+    curve.check_wallet_limits(position.discovery_tokens_bought, wallet_balance)?;
+
+    // Early access: only allowlisted wallets, up to their allocation
+    if curve.in_allowlist_window(current_time) {
+        let proof = allowlist_proof.ok_or(HypeBondError::NotAllowlisted)?;
+        proof.verify(&curve.allowlist_root, &user.key())?;
+        position.record_allowlist_buy(amount, proof.allocation)?;
+    }
 
     // 3. Calculate fee
-    let fee_amount = sol_required
-        .checked_mul(global.fee_basis_points)
-        .unwrap()
-        .checked_div(10000)
-        .unwrap();
-
-    // 4. Transfer SOL: User -> Bonding Curve
-    let transfer_amount = sol_required.checked_add(fee_amount).unwrap();
+    let fee_amount = math::apply_bps(sol_required, global.fee_basis_points, Rounding::Up)?;
+
+    // 4. Transfer SOL: User -> SOL Vault
+    let transfer_amount = sol_required
+        .checked_add(fee_amount)
+        .ok_or(HypeBondError::MathOverflow)?;
     anchor_lang::system_program::transfer(
         CpiContext::new(
             ctx.accounts.system_program.to_account_info(),
             anchor_lang::system_program::Transfer {
                 from: user.to_account_info(),
-                to: ctx.accounts.associated_bonding_curve.to_account_info(),
+                to: ctx.accounts.sol_vault.to_account_info(),
             },
         ),
         transfer_amount
     )?;
 
-    // 5. Transfer fee: Bonding Curve -> Fee Recipient
-    anchor_lang::system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            anchor_lang::system_program::Transfer {
-                from: ctx.accounts.associated_bonding_curve.to_account_info(),
-                to: ctx.accounts.fee_recipient.to_account_info(),
-            },
-        ),
-        fee_amount
+    // 5. Transfer fee: SOL Vault -> Fee Recipient
+    SolVault::withdraw(
+        &ctx.accounts.sol_vault.to_account_info(),
+        &ctx.accounts.fee_recipient.to_account_info(),
+        fee_amount,
     )?;
 
     // 6. Transfer tokens: Bonding Curve -> User
+    let seeds = &[
+        b"bonding-curve".as_ref(),
+        mint_key.as_ref(),
+        &[ctx.bumps.bonding_curve]
+    ];
+    let signer = &[&seeds[..]];
+
     token::transfer(
-        CpiContext::new(
+        CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             token::Transfer {
                 from: ctx.accounts.associated_bonding_curve.to_account_info(),
                 to: ctx.accounts.associated_user.to_account_info(),
                 authority: curve.to_account_info(),
             },
+            signer
         ),
         amount
     )?;
 
     // 7. Update bonding curve state
     curve.update_reserves(amount, sol_required, true)?;
-    curve.update_volume(amount, current_time);
-
-    // 8. Log phase and price information
-    if curve.curve_state == CurveState::PriceDiscovery {
-        msg!("Buy in discovery phase: {} tokens at {} lamports per token", 
-            amount, 
-            curve.get_discovery_price(current_time)?);
-    } else {
-        msg!("Buy in bonding curve phase: {} tokens for {} SOL", 
-            amount, 
-            sol_required as f64 / 1_000_000_000.0);
+    curve.update_volume(amount, current_time, true);
+    SolVault::check_reserves(&ctx.accounts.sol_vault.to_account_info(), curve.real_sol_reserves)?;
+
+    // 8. Emit the trade with post-trade reserves
+    emit_cpi!(TradeEvent {
+        mint: mint_key,
+        bonding_curve: curve.key(),
+        user: user.key(),
+        is_buy: true,
+        phase: LaunchPhase::from(&curve.curve_state),
+        token_amount: amount,
+        sol_amount: sol_required,
+        fee_amount,
+        price: math::price_of(sol_required, amount)?,
+        virtual_token_reserves: curve.virtual_token_reserves,
+        virtual_sol_reserves: curve.virtual_sol_reserves,
+        real_token_reserves: curve.real_token_reserves,
+        real_sol_reserves: curve.real_sol_reserves,
+        timestamp: current_time,
+    });
+
+    // Stop trading once the graduation threshold is crossed
+    if curve.is_completion_reached() {
+        curve.complete = true;
+        emit_cpi!(PhaseTransitionEvent::new(
+            mint_key,
+            curve,
+            LaunchPhase::from(&curve.curve_state),
+            LaunchPhase::Complete,
+            current_time,
+        ));
     }
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Buy<'info> {
     // Global config PDA storing fee and initial parameters
+    #[account(
+        seeds = [b"global"],
+        bump
+    )]
     pub global: Account<'info, Global>,
     
-    // Account that receives trading fees
-    #[account(mut)]
+    /// CHECK: Account that receives trading fees, validated against global
+    #[account(
+        mut,
+        address = global.fee_recipient
+    )]
     pub fee_recipient: UncheckedAccount<'info>,
     
     // Token mint for the trading pair
@@ -110,6 +218,14 @@ pub struct Buy<'info> {
     )]
     pub associated_bonding_curve: Account<'info, TokenAccount>,
     
+    // Program-owned account holding the curve's SOL reserves
+    #[account(
+        mut,
+        seeds = [b"sol-vault", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+    
     // User's token account to receive/send tokens
     #[account(
         mut, 
@@ -122,6 +238,16 @@ pub struct Buy<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
     
+    // Per-wallet position, created on the user's first trade
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + std::mem::size_of::<UserPosition>(),
+        seeds = [b"position", bonding_curve.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub user_position: Account<'info, UserPosition>,
+    
     // Required programs
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
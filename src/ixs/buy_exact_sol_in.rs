@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::{errors::HypeBondError, guards::Deadline, allowlist::AllowlistProof};
+use super::buy::{Buy, begin_buy, execute_buy};
+
+pub fn buy_exact_sol_in(
+    mut ctx: Context<Buy>,
+    sol_amount: u64,
+    min_tokens_out: u64,
+    deadline: Option<Deadline>,
+    max_price_impact_bps: Option<u64>,
+    allowlist_proof: Option<AllowlistProof>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let guard = begin_buy(&mut ctx, &clock, deadline, max_price_impact_bps)?;
+
+    // 1. Find the most tokens sol_amount covers, fee included
+    let curve = &ctx.accounts.bonding_curve;
+    let global = &ctx.accounts.global;
+    let policy = curve.effective_policy(&global.protection_policy);
+    let amount = curve.buy_amount_for_sol(sol_amount, global.fee_basis_points, current_time, &policy);
+    require!(amount > 0, HypeBondError::InvalidTradeSize);
+    require!(amount >= min_tokens_out, HypeBondError::SlippageExceeded);
+
+    // 2. Reprice the amount so the buy is charged exactly as in `buy`; any
+    // rounding dust below sol_amount stays with the user
+    let sol_required = curve.calculate_buy_price(amount, current_time, &policy)?;
+
+    execute_buy(&mut ctx, amount, sol_required, current_time, guard, allowlist_proof)
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::{contexts::*, errors::HypeBondError, events::VoteCastEvent};
+
+pub fn cast_vote(ctx: Context<CastVote>, approve: bool, amount: u64) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let vote_record = &mut ctx.accounts.vote_record;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // 1. Votes are weighted by tokens locked until the vote ends
+    require!(amount > 0, HypeBondError::InvalidVoteAmount);
+    require!(proposal.is_active(current_time), HypeBondError::VotingClosed);
+
+    // 2. Deposit voting tokens: Voter -> Proposal Vault
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.voter_token_account.to_account_info(),
+                to: ctx.accounts.proposal_vault.to_account_info(),
+                authority: ctx.accounts.voter.to_account_info(),
+            },
+        ),
+        amount
+    )?;
+
+    proposal.record_vote(approve, amount)?;
+
+    vote_record.proposal = proposal.key();
+    vote_record.voter = ctx.accounts.voter.key();
+    vote_record.approve = approve;
+    vote_record.amount = amount;
+
+    emit_cpi!(VoteCastEvent {
+        proposal: proposal.key(),
+        milestone: proposal.milestone,
+        voter: vote_record.voter,
+        approve,
+        amount,
+        yes_votes: proposal.yes_votes,
+        no_votes: proposal.no_votes,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.milestone.as_ref(), &[proposal.proposal_id]],
+        bump
+    )]
+    pub proposal: Account<'info, MilestoneProposal>,
+
+    // One vote per holder per proposal
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + std::mem::size_of::<VoteRecord>(),
+        seeds = [b"vote", proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal-vault", proposal.key().as_ref()],
+        bump
+    )]
+    pub proposal_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = proposal_vault.mint,
+        token::authority = voter
+    )]
+    pub voter_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{self, Token, TokenAccount, Mint},
+    associated_token::AssociatedToken,
+};
+use crate::{
+    auction::{Bid, BidBook},
+    curve::BondingCurve,
+    errors::HypeBondError,
+    contexts::SolVault,
+    events::BidClaimedEvent,
+};
+
+pub fn claim_bid(ctx: Context<ClaimBid>, _level: u8) -> Result<()> {
+    let curve = &ctx.accounts.bonding_curve;
+    let bid_book = &mut ctx.accounts.bid_book;
+    let bid = &ctx.accounts.bid;
+
+    // 1. Work out this bid's fill at the clearing price
+    let (tokens, cost) = bid_book.fill_for(bid)?;
+    let refund = bid.sol_amount
+        .checked_sub(cost)
+        .ok_or(HypeBondError::MathOverflow)?;
+
+    // 2. Transfer filled tokens: Bonding Curve -> Bidder
+    if tokens > 0 {
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &[
+            b"bonding-curve".as_ref(),
+            mint_key.as_ref(),
+            &[ctx.bumps.bonding_curve]
+        ];
+        let signer = &[&seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.associated_bonding_curve.to_account_info(),
+                    to: ctx.accounts.associated_user.to_account_info(),
+                    authority: curve.to_account_info(),
+                },
+                signer
+            ),
+            tokens
+        )?;
+    }
+
+    // 3. Refund unfilled SOL: SOL Vault -> Bidder
+    if refund > 0 {
+        SolVault::withdraw(
+            &ctx.accounts.sol_vault.to_account_info(),
+            &ctx.accounts.bidder.to_account_info(),
+            refund,
+        )?;
+    }
+
+    // 4. Filled SOL was credited to the curve at settlement; rounding dust
+    // from per-bid costs stays in the vault on top of the reserves
+    bid_book.reserved_tokens = bid_book.reserved_tokens
+        .checked_sub(tokens)
+        .ok_or(HypeBondError::MathOverflow)?;
+    bid_book.total_bid_sol = bid_book.total_bid_sol
+        .checked_sub(bid.sol_amount)
+        .ok_or(HypeBondError::MathOverflow)?;
+
+    emit_cpi!(BidClaimedEvent {
+        mint: ctx.accounts.mint.key(),
+        bonding_curve: curve.key(),
+        bidder: bid.bidder,
+        level: bid.level,
+        tokens_filled: tokens,
+        sol_cost: cost,
+        sol_refunded: refund,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(level: u8)]
+pub struct ClaimBid<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [b"bonding-curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bonding_curve
+    )]
+    pub associated_bonding_curve: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"sol-vault", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+
+    #[account(
+        mut,
+        seeds = [b"bid-book", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub bid_book: Account<'info, BidBook>,
+
+    // Bid is closed back to the bidder once claimed
+    #[account(
+        mut,
+        close = bidder,
+        seeds = [b"bid", bonding_curve.key().as_ref(), bidder.key().as_ref(), &[level]],
+        bump
+    )]
+    pub bid: Account<'info, Bid>,
+
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        associated_token::mint = mint,
+        associated_token::authority = bidder
+    )]
+    pub associated_user: Account<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
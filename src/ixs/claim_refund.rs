@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{self, Token, TokenAccount, Mint},
+    associated_token::AssociatedToken,
+};
+use crate::{
+    curve::BondingCurve,
+    errors::HypeBondError,
+    contexts::SolVault,
+    events::{LaunchPhase, PhaseTransitionEvent, RefundClaimedEvent},
+};
+
+pub fn claim_refund(ctx: Context<ClaimRefund>, amount: u64) -> Result<()> {
+    let curve = &mut ctx.accounts.bonding_curve;
+    let user = &ctx.accounts.user;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Evaluate the reserve if nobody has traded since discovery ended
+    if curve.check_and_update_state(current_time)? {
+        emit_cpi!(PhaseTransitionEvent::new(
+            ctx.accounts.mint.key(),
+            curve,
+            LaunchPhase::PriceDiscovery,
+            LaunchPhase::from(&curve.curve_state),
+            current_time,
+        ));
+    }
+
+    // 1. Pro rata share of the SOL raised
+    let refund = curve.refund_amount(amount)?;
+
+    // 2. Return tokens: User -> Bonding Curve
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.associated_user.to_account_info(),
+                to: ctx.accounts.associated_bonding_curve.to_account_info(),
+                authority: user.to_account_info(),
+            },
+        ),
+        amount
+    )?;
+
+    // 3. Refund SOL: SOL Vault -> User
+    SolVault::withdraw(
+        &ctx.accounts.sol_vault.to_account_info(),
+        &user.to_account_info(),
+        refund,
+    )?;
+
+    curve.real_token_reserves = curve.real_token_reserves
+        .checked_add(amount)
+        .ok_or(HypeBondError::MathOverflow)?;
+    curve.real_sol_reserves = curve.real_sol_reserves
+        .checked_sub(refund)
+        .ok_or(HypeBondError::MathOverflow)?;
+
+    emit_cpi!(RefundClaimedEvent {
+        mint: ctx.accounts.mint.key(),
+        bonding_curve: curve.key(),
+        user: user.key(),
+        tokens_returned: amount,
+        sol_refunded: refund,
+        real_sol_reserves: curve.real_sol_reserves,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimRefund<'info> {
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"bonding-curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bonding_curve
+    )]
+    pub associated_bonding_curve: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"sol-vault", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = user
+    )]
+    pub associated_user: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
@@ -0,0 +1,274 @@
+use std::str::FromStr;
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use streamflow_sdk::{
+    cpi::accounts::CreateUncheckedWithPayer,
+    state::{METADATA_LEN, STRM_TREASURY},
+};
+use crate::{
+    contexts::*,
+    errors::HypeBondError,
+    events::TeamAllocationClaimedEvent,
+    math::{self, Rounding},
+};
+
+pub fn claim_team_allocation(ctx: Context<ClaimTeamAllocation>, milestone_id: u8) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    let milestone = &ctx.accounts.milestone;
+    let team_claim = &mut ctx.accounts.team_claim;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // 1. Only completed milestones pay out, and only to listed members
+    require!(milestone.completed, HypeBondError::MilestoneNotCompleted);
+    let member = project.team_allocation
+        .member(&ctx.accounts.member.key())
+        .ok_or(HypeBondError::NotTeamMember)?
+        .clone();
+
+    team_claim.milestone = milestone.key();
+    team_claim.member = member.wallet;
+
+    // 2. SOL share of the escrowed tranche: Escrow -> Member
+    let sol_claimed = TeamAllocation::member_amount(&member, milestone.team_sol)?
+        .saturating_sub(team_claim.sol_claimed);
+    if sol_claimed > 0 {
+        SolVault::withdraw(
+            &ctx.accounts.project_escrow.to_account_info(),
+            &ctx.accounts.member.to_account_info(),
+            sol_claimed,
+        )?;
+        team_claim.sol_claimed = team_claim.sol_claimed
+            .checked_add(sol_claimed)
+            .ok_or(HypeBondError::MathOverflow)?;
+    }
+
+    // 3. Token share, once per milestone. Native vesting is claimed through
+    // claim_vested instead
+    let vesting = project.team_vesting;
+    let tokens_owed = if team_claim.tokens_claimed > 0
+        || (vesting.is_enabled() && vesting.mode == VestingMode::Native)
+    {
+        0
+    } else {
+        TeamAllocation::member_amount(&member, milestone.team_tokens)?
+    };
+
+    let seeds = &[
+        b"project".as_ref(),
+        project.name.as_bytes(),
+        project.ticker.as_bytes(),
+        &[ctx.bumps.project]
+    ];
+    let signer = &[&seeds[..]];
+
+    // Streams vest from completion, like the native schedule. Streamflow
+    // can't start in the past, so a late claim pays what has already vested
+    // directly and streams the rest from now at the same rate; later unlocks
+    // trail the native schedule by less than one period
+    let (paid_directly, streamed, start_time) = if tokens_owed > 0 && vesting.is_enabled() {
+        let mut schedule = VestingSchedule::default();
+        schedule.initialize(project.key(), milestone.key(), &vesting, tokens_owed, milestone.completed_at)?;
+        let vested = schedule.vested_amount(current_time)?;
+        (vested, tokens_owed - vested, schedule.start_time.max(current_time) as u64)
+    } else {
+        (tokens_owed, 0, 0)
+    };
+
+    if streamed > 0 {
+        let missing = || error!(HypeBondError::MissingStreamAccounts);
+        let metadata = ctx.accounts.stream_metadata.as_ref().ok_or_else(missing)?;
+        let escrow_tokens = ctx.accounts.stream_escrow_tokens.as_ref().ok_or_else(missing)?;
+        let withdrawor = ctx.accounts.streamflow_withdrawor.as_ref().ok_or_else(missing)?;
+        let fee_oracle = ctx.accounts.streamflow_fee_oracle.as_ref().ok_or_else(missing)?;
+        let streamflow_program = ctx.accounts.streamflow_program.as_ref().ok_or_else(missing)?;
+        let rent = ctx.accounts.rent.as_ref().ok_or_else(missing)?;
+
+        // Streamflow expects a pre-allocated metadata account that it owns
+        system_program::create_account(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: ctx.accounts.member.to_account_info(),
+                    to: metadata.to_account_info(),
+                },
+            ),
+            Rent::get()?.minimum_balance(METADATA_LEN),
+            METADATA_LEN as u64,
+            &streamflow_sdk::ID,
+        )?;
+
+        let amount_per_period = math::mul_div(
+            tokens_owed,
+            vesting.period_seconds,
+            vesting.duration_seconds,
+            Rounding::Up,
+        )?;
+        let partner = Pubkey::from_str(STRM_TREASURY).map_err(|_| ProgramError::InvalidArgument)?;
+
+        streamflow_sdk::cpi::create_unchecked_with_payer(
+            CpiContext::new_with_signer(
+                streamflow_program.to_account_info(),
+                CreateUncheckedWithPayer {
+                    payer: ctx.accounts.member.to_account_info(),
+                    sender: project.to_account_info(),
+                    sender_tokens: ctx.accounts.vault.to_account_info(),
+                    metadata: metadata.to_account_info(),
+                    escrow_tokens: escrow_tokens.to_account_info(),
+                    withdrawor: withdrawor.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    fee_oracle: fee_oracle.to_account_info(),
+                    rent: rent.to_account_info(),
+                    timelock_program: streamflow_program.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+                signer
+            ),
+            start_time,
+            streamed,
+            vesting.period_seconds,
+            amount_per_period,
+            start_time,                 // Cliff: first unlock at the start
+            0,                          // No lump sum at the cliff
+            false,                      // cancelable_by_sender
+            false,                      // cancelable_by_recipient
+            false,                      // automatic_withdrawal
+            false,                      // transferable_by_sender
+            true,                       // transferable_by_recipient
+            false,                      // can_topup
+            stream_name(&project.ticker, milestone_id),
+            vesting.period_seconds,
+            member.wallet,
+            partner,
+            false,                      // pausable
+            false,                      // can_update_rate
+        )?;
+
+        team_claim.stream_id = metadata.key();
+    }
+
+    if paid_directly > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.member_token_account.to_account_info(),
+                    authority: project.to_account_info(),
+                },
+                signer
+            ),
+            paid_directly
+        )?;
+    }
+    team_claim.tokens_claimed = team_claim.tokens_claimed
+        .checked_add(tokens_owed)
+        .ok_or(HypeBondError::MathOverflow)?;
+    project.team_tokens_owed = project.team_tokens_owed
+        .checked_sub(tokens_owed)
+        .ok_or(HypeBondError::MathOverflow)?;
+
+    require!(sol_claimed > 0 || tokens_owed > 0, HypeBondError::NothingToClaim);
+
+    emit_cpi!(TeamAllocationClaimedEvent {
+        project: project.key(),
+        milestone: milestone.key(),
+        milestone_id,
+        member: member.wallet,
+        share_bps: member.share_bps,
+        tokens_claimed: tokens_owed,
+        stream: team_claim.stream_id,
+        sol_claimed,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(milestone_id: u8)]
+pub struct ClaimTeamAllocation<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.name.as_bytes(), project.ticker.as_bytes()],
+        bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"milestone", project.key().as_ref(), &[milestone_id]],
+        bump,
+        constraint = milestone.project == project.key() @ HypeBondError::NotAuthorized
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    #[account(
+        init_if_needed,
+        payer = member,
+        space = 8 + std::mem::size_of::<TeamMemberClaim>(),
+        seeds = [b"team-claim", milestone.key().as_ref(), member.key().as_ref()],
+        bump
+    )]
+    pub team_claim: Account<'info, TeamMemberClaim>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", project.name.as_bytes(), project.ticker.as_bytes()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(address = vault.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = vault.mint,
+        constraint = member_token_account.owner == member.key() @ HypeBondError::NotAuthorized
+    )]
+    pub member_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", project.key().as_ref()],
+        bump
+    )]
+    pub project_escrow: Account<'info, ProjectEscrow>,
+
+    // Streamflow accounts, required only for VestingMode::Streamflow
+    #[account(mut)]
+    pub stream_metadata: Option<Signer<'info>>,
+
+    /// CHECK: Stream escrow token account, derived and created by Streamflow
+    #[account(mut)]
+    pub stream_escrow_tokens: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Streamflow withdrawor, validated by Streamflow
+    #[account(mut)]
+    pub streamflow_withdrawor: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Streamflow fee oracle, validated by Streamflow
+    pub streamflow_fee_oracle: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Streamflow program; pinned since the project PDA signs the CPI
+    #[account(address = streamflow_sdk::ID)]
+    pub streamflow_program: Option<UncheckedAccount<'info>>,
+
+    pub rent: Option<Sysvar<'info, Rent>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+// Streamflow stream names are fixed 64 byte buffers
+fn stream_name(ticker: &str, milestone_id: u8) -> [u8; 64] {
+    let mut name = [0u8; 64];
+    let label = format!("{} milestone {}", ticker, milestone_id);
+    let len = label.len().min(name.len());
+    name[..len].copy_from_slice(&label.as_bytes()[..len]);
+    name
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::{contexts::*, errors::HypeBondError, events::VestedTokensClaimedEvent};
+
+pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    let schedule = &mut ctx.accounts.vesting_schedule;
+    let team_claim = &mut ctx.accounts.team_claim;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // 1. Schedules keep vesting after abandonment, which leaves their
+    // tokens in the vault
+    let member = project.team_allocation
+        .member(&ctx.accounts.member.key())
+        .ok_or(HypeBondError::NotTeamMember)?;
+
+    team_claim.milestone = schedule.milestone;
+    team_claim.member = member.wallet;
+
+    let amount = schedule.claim_share(current_time, member.share_bps, team_claim.tokens_claimed)?;
+    team_claim.tokens_claimed = team_claim.tokens_claimed
+        .checked_add(amount)
+        .ok_or(HypeBondError::MathOverflow)?;
+
+    // 2. Transfer vested tokens: Project Vault -> Member
+    let seeds = &[
+        b"project".as_ref(),
+        project.name.as_bytes(),
+        project.ticker.as_bytes(),
+        &[ctx.bumps.project]
+    ];
+    let signer = &[&seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.member_token_account.to_account_info(),
+                authority: project.to_account_info(),
+            },
+            signer
+        ),
+        amount
+    )?;
+    project.team_tokens_owed = project.team_tokens_owed
+        .checked_sub(amount)
+        .ok_or(HypeBondError::MathOverflow)?;
+
+    emit_cpi!(VestedTokensClaimedEvent {
+        project: project.key(),
+        milestone: schedule.milestone,
+        member: team_claim.member,
+        amount,
+        member_claimed: team_claim.tokens_claimed,
+        schedule_claimed: schedule.claimed_amount,
+        total_amount: schedule.total_amount,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    // Permissionless: anyone can push vested tokens to a member
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.name.as_bytes(), project.ticker.as_bytes()],
+        bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [b"vesting", vesting_schedule.milestone.as_ref()],
+        bump,
+        constraint = vesting_schedule.project == project.key() @ HypeBondError::NotAuthorized
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    /// CHECK: Team member wallet, validated against the project's member list
+    pub member: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + std::mem::size_of::<TeamMemberClaim>(),
+        seeds = [b"team-claim", vesting_schedule.milestone.as_ref(), member.key().as_ref()],
+        bump
+    )]
+    pub team_claim: Account<'info, TeamMemberClaim>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", project.name.as_bytes(), project.ticker.as_bytes()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = vault.mint,
+        constraint = member_token_account.owner == member.key() @ HypeBondError::NotAuthorized
+    )]
+    pub member_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::{contexts::*, errors::HypeBondError};
+use crate::{contexts::*, errors::HypeBondError, events::MilestoneCompletedEvent};
 
 pub fn complete_milestone(ctx: Context<CompleteMilestone>, milestone_id: u8) -> Result<()> {
     let project = &mut ctx.accounts.project;
@@ -24,66 +24,100 @@ pub fn complete_milestone(ctx: Context<CompleteMilestone>, milestone_id: u8) ->
         !milestone.completed,
         HypeBondError::AlreadyInitialized // Could create a more specific error
     );
+
+    // Holders must have approved the milestone. The proposal opened before
+    // the deadline, so a vote that ends after it still counts
+    let current_time = Clock::get()?.unix_timestamp;
+    let proposal = &ctx.accounts.proposal;
+    require!(!project.abandoned, HypeBondError::ProjectAbandoned);
+    require!(proposal.has_ended(current_time), HypeBondError::VotingNotEnded);
+    require!(proposal.has_passed()?, HypeBondError::ProposalNotPassed);
     
     // Mark milestone as completed
     milestone.completed = true;
     
     // Update project completed milestones
     project.completed_milestones += 1;
+
+    // Team tokens stay in the vault until members claim them
+    project.team_tokens_owed = project.team_tokens_owed
+        .checked_add(milestone.team_tokens)
+        .ok_or(HypeBondError::MathOverflow)?;
     
     // Update token details
     token_details.tokens_unlocked += milestone.total_tokens;
     
-    let project_key = project.key();
-    // Transfer team tokens
-    if milestone.team_tokens > 0 {
-        let seeds = &[
-            b"vault".as_ref(),
-            project_key.as_ref(),
-            &[ctx.bumps.vault]
-        ];
-        let signer = &[&seeds[..]];
-        
-        token::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.vault.to_account_info(),
-                    to: ctx.accounts.team_account.to_account_info(),
-                    authority: ctx.accounts.vault.to_account_info(),
-                },
-                signer
-            ),
-            milestone.team_tokens
+    // Vault is owned by the project PDA
+    let seeds = &[
+        b"project".as_ref(),
+        project.name.as_bytes(),
+        project.ticker.as_bytes(),
+        &[ctx.bumps.project]
+    ];
+    let signer = &[&seeds[..]];
+
+    // Team tokens stay in the vault and each member pulls their share with
+    // claim_team_allocation, or claim_vested under native vesting
+    let vesting = project.team_vesting;
+    if milestone.team_tokens > 0 && vesting.is_enabled() && vesting.mode == VestingMode::Native {
+        let schedule = ctx.accounts.vesting_schedule
+            .as_mut()
+            .ok_or(HypeBondError::MissingVestingSchedule)?;
+        schedule.initialize(
+            project.key(),
+            milestone.key(),
+            &vesting,
+            milestone.team_tokens,
+            current_time,
         )?;
     }
+    milestone.completed_at = current_time;
     
     // Transfer treasury tokens
     if milestone.treasury_tokens > 0 {
-        let seeds = &[
-            b"vault".as_ref(),
-            project_key.as_ref(),
-            &[ctx.bumps.vault]
-        ];
-        let signer = &[&seeds[..]];
-        
         token::transfer(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
                 Transfer {
                     from: ctx.accounts.vault.to_account_info(),
                     to: ctx.accounts.treasury_account.to_account_info(),
-                    authority: ctx.accounts.vault.to_account_info(),
+                    authority: project.to_account_info(),
                 },
                 signer
             ),
             milestone.treasury_tokens
         )?;
     }
+
+    // Earmark the SOL tranche unlocked by this milestone; it stays in the
+    // escrow until members claim their shares
+    let escrow = &mut ctx.accounts.project_escrow;
+    let sol_released = escrow.releasable(project.completed_milestones, project.total_milestones)?;
+    if sol_released > 0 {
+        escrow.release(sol_released)?;
+    }
+    milestone.team_sol = sol_released;
+
+    emit_cpi!(MilestoneCompletedEvent {
+        project: project.key(),
+        milestone: milestone.key(),
+        milestone_id,
+        completed_milestones: project.completed_milestones,
+        total_milestones: project.total_milestones,
+        team_tokens: milestone.team_tokens,
+        treasury_tokens: milestone.treasury_tokens,
+        tokens_unlocked: token_details.tokens_unlocked,
+        sol_released,
+        proposal: proposal.key(),
+        yes_votes: proposal.yes_votes,
+        no_votes: proposal.no_votes,
+        timestamp: current_time,
+    });
     
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(milestone_id: u8)]
 pub struct CompleteMilestone<'info> {
@@ -92,13 +126,15 @@ pub struct CompleteMilestone<'info> {
     
     #[account(
         mut,
+        seeds = [b"project", project.name.as_bytes(), project.ticker.as_bytes()],
+        bump,
         constraint = project.authority == authority.key() @ HypeBondError::NotAuthorized
     )]
     pub project: Account<'info, Project>,
     
     #[account(
         mut,
-        seeds = [b"token", project.key().as_ref()],
+        seeds = [b"token", project.name.as_bytes(), project.ticker.as_bytes()],
         bump
     )]
     pub token_details: Account<'info, TokenDetails>,
@@ -110,23 +146,37 @@ pub struct CompleteMilestone<'info> {
         constraint = milestone.project == project.key() @ HypeBondError::NotAuthorized
     )]
     pub milestone: Account<'info, Milestone>,
+
+    #[account(address = milestone.last_proposal @ HypeBondError::MissingProposal)]
+    pub proposal: Account<'info, MilestoneProposal>,
     
     #[account(
         mut,
-        seeds = [b"vault", project.key().as_ref()],
+        seeds = [b"vault", project.name.as_bytes(), project.ticker.as_bytes()],
         bump
     )]
     pub vault: Account<'info, TokenAccount>,
-    
+
+    #[account(mut)]
+    pub treasury_account: Account<'info, TokenAccount>,
+
     #[account(
         mut,
-        constraint = team_account.owner == project.team_allocation.wallet @ HypeBondError::NotAuthorized
+        seeds = [b"escrow", project.key().as_ref()],
+        bump
     )]
-    pub team_account: Account<'info, TokenAccount>,
-    
-    #[account(mut)]
-    pub treasury_account: Account<'info, TokenAccount>,
-    
+    pub project_escrow: Account<'info, ProjectEscrow>,
+
+    // Native vesting schedule, required only for VestingMode::Native
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<VestingSchedule>(),
+        seeds = [b"vesting", milestone.key().as_ref()],
+        bump
+    )]
+    pub vesting_schedule: Option<Account<'info, VestingSchedule>>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
-}
\ No newline at end of file
+}
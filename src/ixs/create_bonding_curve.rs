@@ -0,0 +1,238 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{self, Token, TokenAccount, Mint, Transfer},
+    associated_token::AssociatedToken,
+};
+use crate::{
+    curve::{BondingCurve, ClearingPriceMode, CompletionMode, DecayMode, DiscoveryMode},
+    errors::HypeBondError,
+    contexts::*,
+    events::BondingCurveCreatedEvent,
+};
+
+// Per-launch discovery parameters chosen by the project authority
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BondingCurveParams {
+    pub discovery_start_time: Option<i64>,  // Scheduled start, defaults to creation time
+    pub discovery_duration: i64,        // Duration of discovery phase in seconds
+    pub discovery_start_price: u64,     // Starting price in lamports per token
+    pub discovery_end_price: u64,       // Ending price in lamports per token
+    pub decay_mode: DecayMode,          // Shape of the discovery price path
+    pub discovery_mode: DiscoveryMode,
+    pub auction_token_supply: u64,      // Tokens offered when discovery is a batch auction
+    pub rebalance_epoch_duration: i64,  // Seconds per rebalancing epoch, 0 disables it
+    pub target_tokens_per_epoch: u64,   // Target sale rate the path adapts toward
+    pub rebalance_step_bps: u64,        // Path shift per epoch, in bps of the price range
+    pub clearing_price_mode: ClearingPriceMode,
+    pub reserve_price: u64,             // Minimum clearing price, 0 disables
+    pub min_sol_raise: u64,             // Minimum lamports raised in discovery, 0 disables
+    pub completion_mode: CompletionMode,
+    pub completion_threshold: u64,      // Tokens sold or lamports raised, per completion_mode
+    pub discovery_wallet_cap: u64,      // Max tokens per wallet during discovery, 0 disables
+    pub max_wallet_tokens: u64,         // Max wallet holdings after bonding phase buys, 0 disables
+    pub allowlist_root: [u8; 32],       // Merkle root gating the allowlist window
+    pub allowlist_duration: i64,        // Seconds of allowlist-only buying at the start of discovery
+    pub escrow_share_bps: u64,          // Share of raised SOL escrowed for milestone releases
+}
+
+pub fn create_bonding_curve(
+    ctx: Context<CreateBondingCurve>,
+    params: BondingCurveParams,
+) -> Result<()> {
+    // The window and price path are checked by schedule_discovery, which
+    // set_params also goes through
+    require!(
+        params.rebalance_epoch_duration >= 0
+            && params.rebalance_step_bps <= 10000
+            && params.allowlist_duration >= 0,
+        HypeBondError::InvalidDiscoveryParams
+    );
+
+    let global = &ctx.accounts.global;
+    let project = &ctx.accounts.project;
+    let token_details = &ctx.accounts.token_details;
+    let curve = &mut ctx.accounts.bonding_curve;
+
+    require!(params.escrow_share_bps < 10000, HypeBondError::InvalidEscrowShare);
+
+    ctx.accounts.sol_vault.owner = curve.key();
+    ctx.accounts.project_escrow.project = project.key();
+
+    // Market share is whatever is not reserved for the project (team + treasury)
+    let market_tokens = token_details.total_supply
+        .checked_mul(100 - token_details.project_percentage as u64)
+        .ok_or(HypeBondError::MathOverflow)?
+        / 100;
+
+    require!(
+        params.completion_threshold > 0
+            && (params.completion_mode == CompletionMode::SolRaised
+                || params.completion_threshold <= market_tokens),
+        HypeBondError::InvalidCompletionThreshold
+    );
+    require!(
+        params.discovery_mode == DiscoveryMode::Continuous
+            || (params.auction_token_supply > 0 && params.auction_token_supply <= market_tokens),
+        HypeBondError::InvalidDiscoveryParams
+    );
+    require!(
+        params.allowlist_duration == 0 || params.discovery_mode == DiscoveryMode::Continuous,
+        HypeBondError::InvalidDiscoveryParams
+    );
+
+    // Seed market health parameters from the global config
+    curve.virtual_token_reserves = global.initial_virtual_token_reserves;
+    curve.virtual_sol_reserves = global.initial_virtual_sol_reserves;
+    curve.min_trade_amount = global.min_trade_amount;
+    curve.max_trade_amount = global.max_trade_amount;
+    curve.base_slippage = global.base_slippage;
+    curve.volume_multiplier = global.volume_multiplier;
+    curve.completion_mode = params.completion_mode;
+    curve.completion_threshold = params.completion_threshold;
+    curve.rebalance_epoch_duration = params.rebalance_epoch_duration;
+    curve.target_tokens_per_epoch = params.target_tokens_per_epoch;
+    curve.rebalance_step_bps = params.rebalance_step_bps;
+    curve.clearing_price_mode = params.clearing_price_mode;
+    curve.reserve_price = params.reserve_price;
+    curve.min_sol_raise = params.min_sol_raise;
+    curve.discovery_mode = params.discovery_mode;
+    curve.auction_token_supply = params.auction_token_supply;
+    curve.discovery_wallet_cap = params.discovery_wallet_cap;
+    curve.max_wallet_tokens = params.max_wallet_tokens;
+    curve.allowlist_root = params.allowlist_root;
+    curve.allowlist_duration = params.allowlist_duration;
+    curve.project = project.key();
+    curve.escrow_share_bps = params.escrow_share_bps;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    curve.initialize_discovery(
+        BondingCurve::scheduled_start(params.discovery_start_time, current_time)?,
+        params.discovery_duration,
+        params.discovery_start_price,
+        params.discovery_end_price,
+        params.decay_mode,
+        market_tokens,
+    )?;
+
+    // Move the market share: Project Vault -> Bonding Curve
+    let seeds = &[
+        b"project".as_ref(),
+        project.name.as_bytes(),
+        project.ticker.as_bytes(),
+        &[ctx.bumps.project]
+    ];
+    let signer = &[&seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.associated_bonding_curve.to_account_info(),
+                authority: project.to_account_info(),
+            },
+            signer
+        ),
+        market_tokens
+    )?;
+
+    emit_cpi!(BondingCurveCreatedEvent {
+        mint: ctx.accounts.mint.key(),
+        bonding_curve: curve.key(),
+        project: project.key(),
+        authority: ctx.accounts.authority.key(),
+        market_tokens,
+        discovery_mode: curve.discovery_mode,
+        discovery_start_time: curve.discovery_start_time,
+        discovery_duration: curve.discovery_duration,
+        discovery_start_price: curve.discovery_start_price,
+        discovery_end_price: curve.discovery_end_price,
+        escrow_share_bps: curve.escrow_share_bps,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateBondingCurve<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Global config PDA storing fee and initial parameters
+    #[account(
+        seeds = [b"global"],
+        bump,
+        constraint = global.initialized @ HypeBondError::NotInitialized
+    )]
+    pub global: Account<'info, Global>,
+
+    #[account(
+        seeds = [b"project", project.name.as_bytes(), project.ticker.as_bytes()],
+        bump,
+        constraint = project.authority == authority.key() @ HypeBondError::NotAuthorized
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"token", project.name.as_bytes(), project.ticker.as_bytes()],
+        bump,
+        constraint = token_details.project == project.key() @ HypeBondError::NotAuthorized
+    )]
+    pub token_details: Account<'info, TokenDetails>,
+
+    #[account(address = token_details.mint)]
+    pub mint: Account<'info, Mint>,
+
+    // Project vault holding the full minted supply
+    #[account(
+        mut,
+        seeds = [b"vault", project.name.as_bytes(), project.ticker.as_bytes()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    // PDA storing bonding curve state and reserves
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<BondingCurve>(),
+        seeds = [b"bonding-curve", mint.key().as_ref()],
+        bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    // Token account owned by bonding curve (holds tokens)
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = bonding_curve
+    )]
+    pub associated_bonding_curve: Account<'info, TokenAccount>,
+
+    // Program-owned account holding the curve's SOL reserves
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<SolVault>(),
+        seeds = [b"sol-vault", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+
+    // Program-owned account holding the project's escrowed SOL
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<ProjectEscrow>(),
+        seeds = [b"escrow", project.key().as_ref()],
+        bump
+    )]
+    pub project_escrow: Account<'info, ProjectEscrow>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -6,6 +6,7 @@ pub fn create_milestone(
     milestone_id: u8,
     description: String,
     requirements: Vec<String>,
+    deadline: i64,
 ) -> Result<()> {
     let project = &ctx.accounts.project;
     let token_details = &ctx.accounts.token_details;
@@ -16,6 +17,10 @@ pub fn create_milestone(
         milestone_id < project.total_milestones,
         HypeBondError::InvalidMilestoneCount
     );
+    require!(
+        deadline == 0 || deadline > Clock::get()?.unix_timestamp,
+        HypeBondError::InvalidMilestoneDeadline
+    );
 
     // Set milestone data
     milestone.project = project.key();
@@ -23,13 +28,13 @@ pub fn create_milestone(
     milestone.description = description;
     milestone.requirements = requirements;
     milestone.completed = false;
+    milestone.deadline = deadline;
 
     // Calculate token allocations for this milestone
     let milestone_tokens = token_details.total_supply * project.unlock_per_milestone / 10000;
     
     // Calculate token distribution based on project percentages
     let project_tokens = milestone_tokens * token_details.project_percentage as u64 / 100;
-    let market_tokens = milestone_tokens - project_tokens;
     
     // Calculate team and treasury tokens from project tokens
     let team_tokens = project_tokens * project.team_allocation.percentage as u64 / 100;
@@ -56,7 +61,7 @@ pub struct CreateMilestone<'info> {
     pub project: Account<'info, Project>,
     
     #[account(
-        seeds = [b"token", project.key().as_ref()],
+        seeds = [b"token", project.name.as_bytes(), project.ticker.as_bytes()],
         bump
     )]
     pub token_details: Account<'info, TokenDetails>,
@@ -64,7 +69,7 @@ pub struct CreateMilestone<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + 32 + 1 + 500 + 4 + 1 + 8 + 8 + 8, // Adjust for your needs
+        space = 8 + 32 + 1 + 500 + 4 + 1 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 32 + 8, // Adjust for your needs
         seeds = [b"milestone", project.key().as_ref(), &[milestone_id]],
         bump
     )]
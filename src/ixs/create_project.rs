@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{spl_token::instruction::AuthorityType, Mint, MintTo, SetAuthority, Token, TokenAccount};
-use crate::{contexts::*, errors::HypeBondError};
+use crate::{contexts::*, errors::HypeBondError, events::ProjectCreatedEvent};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(name: String, ticker: String)]
 pub struct CreateProject<'info> {
@@ -11,7 +12,7 @@ pub struct CreateProject<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + 32 + 100 + 10 + 500 + 1 + 1 + 8 + 32 + 1, // Adjust for TeamAllocation size
+        space = 8 + 32 + 100 + 10 + 500 + 1 + 1 + 8 + TeamAllocation::SIZE + 1 + VestingConfig::SIZE + GovernanceConfig::SIZE + 8,
         seeds = [b"project", name.as_bytes(), ticker.as_bytes()],
         bump
     )]
@@ -38,14 +39,15 @@ pub struct CreateProject<'info> {
     )]
     pub mint: Account<'info, Mint>,
     
-    // Fix: Don't use vault as its own authority and don't reference project.key()
+    // Vault is custodied by the project PDA so locked tokens can only leave
+    // through program instructions (curve seeding, milestone unlocks)
     #[account(
         init,
         payer = authority,
         seeds = [b"vault", name.as_bytes(), ticker.as_bytes()],
         bump,
         token::mint = mint,
-        token::authority = authority,
+        token::authority = project,
     )]
     pub vault: Account<'info, TokenAccount>,
     
@@ -64,8 +66,13 @@ pub fn create_project(
     team_percentage: u8,    // Percentage of non-market tokens that go to team
     treasury_percentage: u8, // Percentage of non-market tokens that go to treasury
     team_allocation: TeamAllocation, // should optionally be a squads multisig
+    team_vesting: VestingConfig,
+    governance: GovernanceConfig,
 ) -> Result<()> {
     require!(team_percentage + treasury_percentage < 100, HypeBondError::InvalidPercentages);
+    require!(team_vesting.is_valid(), HypeBondError::InvalidVestingConfig);
+    require!(team_allocation.is_valid(), HypeBondError::InvalidTeamAllocation);
+    require!(governance.is_valid(), HypeBondError::InvalidGovernanceConfig);
     require!(total_milestones > 3, HypeBondError::InvalidMilestoneCount);
 
     let project = &mut ctx.accounts.project;
@@ -78,6 +85,9 @@ pub fn create_project(
     project.total_milestones = total_milestones;
     project.completed_milestones = 0;
     project.team_allocation = team_allocation;
+    project.team_vesting = team_vesting;
+    project.governance = governance;
+    project.team_tokens_owed = 0;
 
     let unlock_per_milestone = 10000 / total_milestones as u64;
     project.unlock_per_milestone = unlock_per_milestone;
@@ -132,5 +142,19 @@ pub fn create_project(
         None,  // Setting to None removes the freeze authority
     )?;
 
+    emit_cpi!(ProjectCreatedEvent {
+        project: project.key(),
+        authority: project.authority,
+        mint: token_details.mint,
+        vault: ctx.accounts.vault.key(),
+        name: project.name.clone(),
+        ticker: project.ticker.clone(),
+        total_supply: token_details.total_supply,
+        total_milestones: project.total_milestones,
+        project_percentage: token_details.project_percentage,
+        unlock_per_milestone: project.unlock_per_milestone,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
    Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,221 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{self, Token, TokenAccount, Mint},
+    associated_token::AssociatedToken,
+};
+use crate::{
+    curve::{BondingCurve, CurveState},
+    pool::Pool,
+    errors::HypeBondError,
+    contexts::{Global, SolVault, ProjectEscrow},
+    math::{self, Rounding},
+    events::{LaunchPhase, PhaseTransitionEvent},
+};
+
+pub fn graduate(ctx: Context<Graduate>) -> Result<()> {
+    let curve = &mut ctx.accounts.bonding_curve;
+    let global = &ctx.accounts.global;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // 1. Close discovery first so the reserve price and minimum raise are
+    // checked, then flip the curve to complete once its threshold is met
+    if curve.check_and_update_state(current_time)? {
+        emit_cpi!(PhaseTransitionEvent::new(
+            ctx.accounts.mint.key(),
+            curve,
+            LaunchPhase::PriceDiscovery,
+            LaunchPhase::from(&curve.curve_state),
+            current_time,
+        ));
+    }
+    require!(curve.curve_state != CurveState::Failed, HypeBondError::CurveFailed);
+    require!(
+        curve.curve_state == CurveState::BondingCurve,
+        HypeBondError::DiscoveryNotEnded
+    );
+    require!(
+        curve.complete || curve.is_completion_reached(),
+        HypeBondError::BondingCurveNotComplete
+    );
+    curve.complete = true;
+
+    // Seed the pool at the curve's closing price so graduation leaves no
+    // gap to arbitrage. SOL the price can't pair joins the escrow and
+    // unpaired tokens are burned
+    let escrow_share = math::apply_bps(curve.real_sol_reserves, curve.escrow_share_bps, Rounding::Down)?;
+    let market_sol = curve.real_sol_reserves
+        .checked_sub(escrow_share)
+        .ok_or(HypeBondError::MathOverflow)?;
+    let (token_amount, sol_amount) = curve.graduation_liquidity(market_sol)?;
+    let escrow_amount = curve.real_sol_reserves
+        .checked_sub(sol_amount)
+        .ok_or(HypeBondError::MathOverflow)?;
+    let tokens_burned = curve.real_token_reserves
+        .checked_sub(token_amount)
+        .ok_or(HypeBondError::MathOverflow)?;
+
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[
+        b"bonding-curve".as_ref(),
+        mint_key.as_ref(),
+        &[ctx.bumps.bonding_curve]
+    ];
+    let signer = &[&seeds[..]];
+
+    if tokens_burned > 0 {
+        token::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.associated_bonding_curve.to_account_info(),
+                    authority: curve.to_account_info(),
+                },
+                signer
+            ),
+            tokens_burned
+        )?;
+    }
+
+    // 2. Transfer remaining tokens: Bonding Curve -> Pool
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.associated_bonding_curve.to_account_info(),
+                to: ctx.accounts.pool_token_account.to_account_info(),
+                authority: curve.to_account_info(),
+            },
+            signer
+        ),
+        token_amount
+    )?;
+
+    // 3. Transfer raised SOL: Curve SOL Vault -> Pool SOL Vault
+    SolVault::withdraw(
+        &ctx.accounts.sol_vault.to_account_info(),
+        &ctx.accounts.pool_sol_vault.to_account_info(),
+        sol_amount,
+    )?;
+
+    // 4. Escrow the project's share and unpaired SOL: Curve SOL Vault -> Project Escrow
+    SolVault::withdraw(
+        &ctx.accounts.sol_vault.to_account_info(),
+        &ctx.accounts.project_escrow.to_account_info(),
+        escrow_amount,
+    )?;
+    ctx.accounts.project_escrow.deposit(escrow_amount)?;
+
+    curve.real_token_reserves = 0;
+    curve.real_sol_reserves = 0;
+
+    // 5. Seed the constant product pool
+    let pool = &mut ctx.accounts.pool;
+    ctx.accounts.pool_sol_vault.owner = pool.key();
+    pool.initialize(
+        mint_key,
+        ctx.accounts.lp_mint.key(),
+        token_amount,
+        sol_amount,
+        global.fee_basis_points,
+    )?;
+
+    emit_cpi!(PhaseTransitionEvent::new(
+        mint_key,
+        curve,
+        LaunchPhase::Complete,
+        LaunchPhase::Graduated,
+        current_time,
+    ));
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Graduate<'info> {
+    // Anyone can graduate a completed curve and pay for the pool accounts
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"global"],
+        bump
+    )]
+    pub global: Account<'info, Global>,
+
+    // Mutable so unpaired curve tokens can be burned
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"bonding-curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bonding_curve
+    )]
+    pub associated_bonding_curve: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"sol-vault", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", bonding_curve.project.as_ref()],
+        bump
+    )]
+    pub project_escrow: Account<'info, ProjectEscrow>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<Pool>(),
+        seeds = [b"pool", mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    // Token account owned by the pool (holds tokens)
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = pool
+    )]
+    pub pool_token_account: Account<'info, TokenAccount>,
+
+    // Program-owned account holding the pool's SOL reserves
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<SolVault>(),
+        seeds = [b"sol-vault", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_sol_vault: Account<'info, SolVault>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"lp-mint", pool.key().as_ref()],
+        bump,
+        mint::decimals = 6,
+        mint::authority = pool,
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -5,6 +5,7 @@ pub fn initialize(ctx: Context<Initialize>) -> anchor_lang::Result<()> {
     let global = &mut ctx.accounts.global;
     global.initialized = true;
     global.authority = ctx.accounts.user.key();
+    global.protection_policy = ProtectionPolicy::default();
     Ok(())
 }
 
@@ -5,6 +5,28 @@ mod complete_milestone;
 mod buy;
 mod sell;
 mod set_params;
+mod create_bonding_curve;
+mod graduate;
+mod swap;
+mod add_liquidity;
+mod remove_liquidity;
+mod place_bid;
+mod settle_auction;
+mod claim_bid;
+mod claim_refund;
+mod set_protection_policy;
+mod set_curve_protection_policy;
+mod quote;
+mod buy_exact_sol_in;
+mod sell_exact_sol_out;
+mod abandon_project;
+mod redeem;
+mod claim_vested;
+mod claim_team_allocation;
+mod propose_milestone;
+mod cast_vote;
+mod withdraw_vote;
+mod release_escrow;
 
 // Explicitly export the instruction functions
 pub use initialize::*;
@@ -13,4 +35,26 @@ pub use create_milestone::*;
 pub use complete_milestone::*;
 pub use buy::*;
 pub use sell::*;
-pub use set_params::*;
\ No newline at end of file
+pub use set_params::*;
+pub use create_bonding_curve::*;
+pub use graduate::*;
+pub use swap::*;
+pub use add_liquidity::*;
+pub use remove_liquidity::*;
+pub use place_bid::*;
+pub use settle_auction::*;
+pub use claim_bid::*;
+pub use claim_refund::*;
+pub use set_protection_policy::*;
+pub use set_curve_protection_policy::*;
+pub use quote::*;
+pub use buy_exact_sol_in::*;
+pub use sell_exact_sol_out::*;
+pub use abandon_project::*;
+pub use redeem::*;
+pub use claim_vested::*;
+pub use claim_team_allocation::*;
+pub use propose_milestone::*;
+pub use cast_vote::*;
+pub use withdraw_vote::*;
+pub use release_escrow::*;
\ No newline at end of file
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::{
+    auction::{Bid, BidBook},
+    constants::BID_PRICE_LEVELS,
+    curve::{BondingCurve, CurveState, DiscoveryMode},
+    errors::HypeBondError,
+    contexts::SolVault,
+    events::BidPlacedEvent,
+};
+
+pub fn place_bid(ctx: Context<PlaceBid>, level: u8, sol_amount: u64) -> Result<()> {
+    let curve = &ctx.accounts.bonding_curve;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // 1. Bids are only accepted while a batch auction is open
+    require!(
+        curve.discovery_mode == DiscoveryMode::BatchAuction,
+        HypeBondError::WrongDiscoveryMode
+    );
+    require!(
+        curve.curve_state == CurveState::PriceDiscovery
+            && current_time < curve.discovery_end_time(),
+        HypeBondError::DiscoveryEnded
+    );
+    curve.check_started(current_time)?;
+    require!((level as usize) < BID_PRICE_LEVELS, HypeBondError::InvalidBidLevel);
+    require!(sol_amount > 0, HypeBondError::InvalidTradeSize);
+
+    // 2. Escrow the bid: User -> SOL Vault
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.bidder.to_account_info(),
+                to: ctx.accounts.sol_vault.to_account_info(),
+            },
+        ),
+        sol_amount
+    )?;
+
+    // 3. Record the bid in the book
+    let bid_book = &mut ctx.accounts.bid_book;
+    bid_book.bonding_curve = curve.key();
+    bid_book.add_bid(level, sol_amount)?;
+
+    let bid = &mut ctx.accounts.bid;
+    bid.bonding_curve = curve.key();
+    bid.bidder = ctx.accounts.bidder.key();
+    bid.level = level;
+    bid.sol_amount = bid.sol_amount
+        .checked_add(sol_amount)
+        .ok_or(HypeBondError::MathOverflow)?;
+
+    emit_cpi!(BidPlacedEvent {
+        mint: ctx.accounts.mint.key(),
+        bonding_curve: curve.key(),
+        bidder: bid.bidder,
+        level,
+        price: BidBook::level_price(curve, level)?,
+        sol_amount,
+        bid_sol: bid.sol_amount,
+        total_bid_sol: bid_book.total_bid_sol,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(level: u8)]
+pub struct PlaceBid<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [b"bonding-curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    // Program-owned account holding the curve's SOL, including open bids
+    #[account(
+        mut,
+        seeds = [b"sol-vault", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+
+    // Per-curve book aggregating bids by price level
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + std::mem::size_of::<BidBook>(),
+        seeds = [b"bid-book", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub bid_book: Account<'info, BidBook>,
+
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + std::mem::size_of::<Bid>(),
+        seeds = [b"bid", bonding_curve.key().as_ref(), bidder.key().as_ref(), &[level]],
+        bump
+    )]
+    pub bid: Account<'info, Bid>,
+
+    pub system_program: Program<'info, System>,
+}
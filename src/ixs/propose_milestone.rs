@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::{contexts::*, errors::HypeBondError, events::ProposalCreatedEvent};
+
+pub fn propose_milestone(ctx: Context<ProposeMilestone>, milestone_id: u8) -> Result<()> {
+    let project = &ctx.accounts.project;
+    let milestone = &mut ctx.accounts.milestone;
+    let proposal = &mut ctx.accounts.proposal;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // 1. Proposals must open before the deadline, one vote at a time
+    require!(!project.abandoned, HypeBondError::ProjectAbandoned);
+    require!(!milestone.completed, HypeBondError::AlreadyInitialized);
+    require!(
+        !milestone.is_overdue(current_time),
+        HypeBondError::MilestoneDeadlinePassed
+    );
+    require!(
+        current_time >= milestone.voting_ends_at,
+        HypeBondError::ProposalActive
+    );
+
+    // 2. Only tokens holders can deposit count: the vault, the curve's unsold
+    // and reserved auction tokens and the pool's liquidity can never vote
+    let mint_key = ctx.accounts.mint.key();
+    let curve_tokens = locked_balance(&ctx.accounts.bonding_curve_tokens, b"bonding-curve", &mint_key)?;
+    let pool_tokens = locked_balance(&ctx.accounts.pool_tokens, b"pool", &mint_key)?;
+    let locked_tokens = ctx.accounts.vault.amount
+        .checked_add(curve_tokens)
+        .and_then(|locked| locked.checked_add(pool_tokens))
+        .ok_or(HypeBondError::MathOverflow)?;
+    let eligible_supply = ctx.accounts.mint.supply
+        .checked_sub(locked_tokens)
+        .ok_or(HypeBondError::MathOverflow)?;
+    let governance = project.governance;
+
+    proposal.project = project.key();
+    proposal.milestone = milestone.key();
+    proposal.proposal_id = milestone.proposal_count;
+    proposal.proposer = ctx.accounts.authority.key();
+    proposal.eligible_supply = eligible_supply;
+    proposal.quorum_bps = governance.quorum_bps;
+    proposal.approval_bps = governance.approval_bps;
+    proposal.voting_start = current_time;
+    proposal.voting_end = current_time
+        .checked_add(governance.voting_period)
+        .ok_or(HypeBondError::MathOverflow)?;
+
+    milestone.proposal_count = milestone.proposal_count
+        .checked_add(1)
+        .ok_or(HypeBondError::MathOverflow)?;
+    milestone.last_proposal = proposal.key();
+    milestone.voting_ends_at = proposal.voting_end;
+
+    emit_cpi!(ProposalCreatedEvent {
+        project: project.key(),
+        milestone: milestone.key(),
+        milestone_id,
+        proposal: proposal.key(),
+        proposal_id: proposal.proposal_id,
+        eligible_supply,
+        quorum_bps: proposal.quorum_bps,
+        approval_bps: proposal.approval_bps,
+        voting_start: proposal.voting_start,
+        voting_end: proposal.voting_end,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(milestone_id: u8)]
+pub struct ProposeMilestone<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"project", project.name.as_bytes(), project.ticker.as_bytes()],
+        bump,
+        constraint = project.authority == authority.key() @ HypeBondError::NotAuthorized
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [b"milestone", project.key().as_ref(), &[milestone_id]],
+        bump,
+        constraint = milestone.project == project.key() @ HypeBondError::NotAuthorized
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<MilestoneProposal>(),
+        seeds = [b"proposal", milestone.key().as_ref(), &[milestone.proposal_count]],
+        bump
+    )]
+    pub proposal: Account<'info, MilestoneProposal>,
+
+    #[account(
+        seeds = [b"vault", project.name.as_bytes(), project.ticker.as_bytes()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(address = vault.mint)]
+    pub mint: Account<'info, Mint>,
+
+    // Holds voters' deposits until the vote ends
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"proposal-vault", proposal.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = proposal,
+    )]
+    pub proposal_vault: Account<'info, TokenAccount>,
+
+    // Curve and pool token accounts, required once they exist
+    #[account(token::mint = mint)]
+    pub bonding_curve_tokens: Option<Account<'info, TokenAccount>>,
+
+    #[account(token::mint = mint)]
+    pub pool_tokens: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+// Balance of a token account owned by one of this program's per-mint PDAs
+pub(crate) fn locked_balance(account: &Option<Account<TokenAccount>>, seed: &[u8], mint: &Pubkey) -> Result<u64> {
+    let Some(account) = account else {
+        return Ok(0);
+    };
+    let (owner, _) = Pubkey::find_program_address(&[seed, mint.as_ref()], &crate::ID);
+    require_keys_eq!(account.owner, owner, HypeBondError::NotAuthorized);
+    Ok(account.amount)
+}
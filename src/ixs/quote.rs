@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::{
+    curve::BondingCurve,
+    errors::HypeBondError,
+    math::{self, Rounding},
+    contexts::Global,
+    events::LaunchPhase,
+};
+
+// Result of pricing a trade without executing it, returned via return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct TradeQuote {
+    pub phase: LaunchPhase,        // Phase the trade would execute in
+    pub token_amount: u64,
+    pub sol_amount: u64,           // Lamports paid or received before fees
+    pub fee_amount: u64,
+    pub net_sol_amount: u64,       // Total paid for a buy, received for a sell
+    pub price: u64,                // Average execution price
+    pub price_impact_bps: u64,     // Adverse move of the execution price from spot_price_before
+    pub spot_price_before: u64,
+    pub spot_price_after: u64,
+}
+
+pub fn quote_buy(ctx: Context<GetQuote>, amount: u64) -> Result<TradeQuote> {
+    quote_trade(&ctx.accounts.global, &ctx.accounts.bonding_curve, amount, true)
+}
+
+pub fn quote_sell(ctx: Context<GetQuote>, amount: u64) -> Result<TradeQuote> {
+    quote_trade(&ctx.accounts.global, &ctx.accounts.bonding_curve, amount, false)
+}
+
+// Run the buy/sell pricing against a copy of the curve so nothing is written
+fn quote_trade(global: &Global, bonding_curve: &BondingCurve, amount: u64, is_buy: bool) -> Result<TradeQuote> {
+    let mut curve = bonding_curve.clone();
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // 1. Apply any pending phase transition the trade would trigger; impact is
+    // measured from the price before it, matching the buy/sell price guard
+    let spot_price_before = curve.marginal_price(current_time)?;
+    curve.check_and_update_state(current_time)?;
+    curve.check_tradable(current_time)?;
+    if !is_buy {
+        curve.check_trade_size(amount)?;
+    }
+
+    // 2. Price the trade exactly as buy/sell would
+    let policy = curve.effective_policy(&global.protection_policy);
+    let sol_amount = if is_buy {
+        curve.calculate_buy_price(amount, current_time, &policy)?
+    } else {
+        curve.calculate_sell_price(amount, current_time, &policy)?
+    };
+    let fee_amount = math::apply_bps(sol_amount, global.fee_basis_points, Rounding::Up)?;
+    let net_sol_amount = if is_buy {
+        sol_amount.checked_add(fee_amount).ok_or(HypeBondError::MathOverflow)?
+    } else {
+        sol_amount.checked_sub(fee_amount).ok_or(HypeBondError::MathOverflow)?
+    };
+    let price = math::price_of(sol_amount, amount)?;
+
+    // 3. Simulate the reserve update to find where the price lands
+    let phase = LaunchPhase::from(&curve.curve_state);
+    curve.update_reserves(amount, sol_amount, is_buy)?;
+
+    Ok(TradeQuote {
+        phase,
+        token_amount: amount,
+        sol_amount,
+        fee_amount,
+        net_sol_amount,
+        price,
+        price_impact_bps: math::price_impact_bps(spot_price_before, price, is_buy)?,
+        spot_price_before,
+        spot_price_after: curve.marginal_price(current_time)?,
+    })
+}
+
+#[derive(Accounts)]
+pub struct GetQuote<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump
+    )]
+    pub global: Account<'info, Global>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [b"bonding-curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+}
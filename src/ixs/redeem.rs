@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+use crate::{contexts::*, errors::HypeBondError, events::RedemptionEvent};
+
+pub fn redeem(ctx: Context<Redeem>, amount: u64) -> Result<()> {
+    let escrow = &mut ctx.accounts.project_escrow;
+    let holder = &ctx.accounts.holder;
+
+    // 1. Pro-rata share of the escrow against the supply that can redeem
+    let sol_redeemed = escrow.redemption_amount(amount)?;
+
+    // Don't burn tokens for nothing: an empty escrow or a dust amount
+    require!(sol_redeemed > 0, HypeBondError::NothingToClaim);
+
+    // 2. Burn the holder's tokens
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.holder_token_account.to_account_info(),
+                authority: holder.to_account_info(),
+            },
+        ),
+        amount
+    )?;
+
+    // 3. Pay out SOL: Project Escrow -> Holder
+    SolVault::withdraw(
+        &escrow.to_account_info(),
+        &holder.to_account_info(),
+        sol_redeemed,
+    )?;
+    escrow.redeem(amount, sol_redeemed)?;
+
+    emit_cpi!(RedemptionEvent {
+        project: ctx.accounts.project.key(),
+        holder: holder.key(),
+        tokens_burned: amount,
+        sol_redeemed,
+        escrow_remaining: escrow.remaining(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Redeem<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        seeds = [b"project", project.name.as_bytes(), project.ticker.as_bytes()],
+        bump,
+        constraint = project.abandoned @ HypeBondError::ProjectNotAbandoned
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"token", project.name.as_bytes(), project.ticker.as_bytes()],
+        bump
+    )]
+    pub token_details: Account<'info, TokenDetails>,
+
+    #[account(
+        mut,
+        address = token_details.mint
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = holder
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", project.key().as_ref()],
+        bump
+    )]
+    pub project_escrow: Account<'info, ProjectEscrow>,
+
+    pub token_program: Program<'info, Token>,
+}
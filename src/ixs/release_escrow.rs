@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::{contexts::*, errors::HypeBondError, events::EscrowReleasedEvent};
+
+pub fn release_escrow(ctx: Context<ReleaseEscrow>, milestone_id: u8) -> Result<()> {
+    let project = &ctx.accounts.project;
+    let milestone = &mut ctx.accounts.milestone;
+    let escrow = &mut ctx.accounts.project_escrow;
+
+    // 1. Completions release the escrow, so SOL deposited after the final
+    // one (a late graduation) would otherwise stay locked
+    require!(milestone.completed, HypeBondError::MilestoneNotCompleted);
+    require!(
+        project.completed_milestones >= project.total_milestones,
+        HypeBondError::MilestonesIncomplete
+    );
+
+    let sol_released = escrow.releasable(project.completed_milestones, project.total_milestones)?;
+    require!(sol_released > 0, HypeBondError::NothingToClaim);
+
+    // 2. Earmark it on a completed milestone; members pull their shares with
+    // claim_team_allocation
+    escrow.release(sol_released)?;
+    milestone.team_sol = milestone.team_sol
+        .checked_add(sol_released)
+        .ok_or(HypeBondError::MathOverflow)?;
+
+    emit_cpi!(EscrowReleasedEvent {
+        project: project.key(),
+        milestone: milestone.key(),
+        milestone_id,
+        sol_released,
+        team_sol: milestone.team_sol,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(milestone_id: u8)]
+pub struct ReleaseEscrow<'info> {
+    // Permissionless: anyone can release once every milestone is complete
+    #[account(
+        seeds = [b"project", project.name.as_bytes(), project.ticker.as_bytes()],
+        bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [b"milestone", project.key().as_ref(), &[milestone_id]],
+        bump,
+        constraint = milestone.project == project.key() @ HypeBondError::NotAuthorized
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", project.key().as_ref()],
+        bump
+    )]
+    pub project_escrow: Account<'info, ProjectEscrow>,
+}
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{self, Token, TokenAccount, Mint, Burn},
+    associated_token::AssociatedToken,
+};
+use crate::{pool::Pool, errors::HypeBondError, contexts::SolVault, events::LiquidityEvent};
+
+pub fn remove_liquidity(
+    ctx: Context<RemoveLiquidity>,
+    lp_amount: u64,
+    min_token_amount: u64,
+    min_sol_amount: u64,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let user = &ctx.accounts.user;
+
+    // 1. Withdrawal is pro rata to the LP share
+    let (token_amount, sol_amount) = pool.calculate_withdrawal(lp_amount)?;
+    require!(
+        token_amount >= min_token_amount && sol_amount >= min_sol_amount,
+        HypeBondError::SlippageExceeded
+    );
+
+    // 2. Burn the user's LP tokens
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.lp_mint.to_account_info(),
+                from: ctx.accounts.user_lp_account.to_account_info(),
+                authority: user.to_account_info(),
+            },
+        ),
+        lp_amount
+    )?;
+
+    // 3. Transfer tokens: Pool -> User
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[
+        b"pool".as_ref(),
+        mint_key.as_ref(),
+        &[ctx.bumps.pool]
+    ];
+    let signer = &[&seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.pool_token_account.to_account_info(),
+                to: ctx.accounts.associated_user.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            signer
+        ),
+        token_amount
+    )?;
+
+    // 4. Transfer SOL: Pool SOL Vault -> User
+    SolVault::withdraw(
+        &ctx.accounts.pool_sol_vault.to_account_info(),
+        &user.to_account_info(),
+        sol_amount,
+    )?;
+
+    pool.withdraw(lp_amount, token_amount, sol_amount)?;
+
+    emit_cpi!(LiquidityEvent {
+        mint: mint_key,
+        pool: pool.key(),
+        user: user.key(),
+        is_deposit: false,
+        lp_amount,
+        token_amount,
+        sol_amount,
+        lp_supply: pool.lp_supply,
+        token_reserves: pool.token_reserves,
+        sol_reserves: pool.sol_reserves,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveLiquidity<'info> {
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"pool", mint.key().as_ref()],
+        bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = pool
+    )]
+    pub pool_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"sol-vault", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_sol_vault: Account<'info, SolVault>,
+
+    #[account(
+        mut,
+        seeds = [b"lp-mint", pool.key().as_ref()],
+        bump
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = user
+    )]
+    pub associated_user: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = user
+    )]
+    pub user_lp_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
@@ -3,34 +3,89 @@ use anchor_spl::{
     token::{self, Token, TokenAccount, Mint},
     associated_token::AssociatedToken,
 };
-use crate::{curve::{BondingCurve, CurveState}, errors::HypeBondError, contexts::Global};
+use crate::{
+    curve::BondingCurve,
+    errors::HypeBondError,
+    math::{self, Rounding},
+    contexts::{Global, SolVault, UserPosition},
+    events::{LaunchPhase, PhaseTransitionEvent, TradeEvent},
+    guards::{Deadline, PriceGuard},
+};
+
+pub fn sell(
+    mut ctx: Context<Sell>,
+    amount: u64,
+    min_sol_output: u64,
+    deadline: Option<Deadline>,
+    max_price_impact_bps: Option<u64>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let guard = begin_sell(&mut ctx, &clock, deadline, max_price_impact_bps)?;
+    ctx.accounts.bonding_curve.check_trade_size(amount)?;
+
+    // 2. Calculate SOL output using appropriate formula based on phase
+    let curve = &ctx.accounts.bonding_curve;
+    let policy = curve.effective_policy(&ctx.accounts.global.protection_policy);
+    let sol_output = curve.calculate_sell_price(amount, current_time, &policy)?;
+    require!(sol_output >= min_sol_output, HypeBondError::TooLittleSolReceived);
+
+    execute_sell(&mut ctx, amount, sol_output, current_time, guard)
+}
+
+// 1. Apply any pending phase transition and check the curve accepts sells,
+// capturing the price the trader signed against before anything moves
+pub(crate) fn begin_sell(
+    ctx: &mut Context<Sell>,
+    clock: &Clock,
+    deadline: Option<Deadline>,
+    max_price_impact_bps: Option<u64>,
+) -> Result<PriceGuard> {
+    Deadline::check(deadline, clock)?;
+
+    let current_time = clock.unix_timestamp;
+    let curve = &mut ctx.accounts.bonding_curve;
+    let guard = PriceGuard {
+        reference_price: curve.marginal_price(current_time)?,
+        max_price_impact_bps,
+    };
+
+    if curve.check_and_update_state(current_time)? {
+        emit_cpi!(PhaseTransitionEvent::new(
+            ctx.accounts.mint.key(),
+            curve,
+            LaunchPhase::PriceDiscovery,
+            LaunchPhase::from(&curve.curve_state),
+            current_time,
+        ));
+    }
+
+    curve.check_tradable(current_time)?;
+    Ok(guard)
+}
+
+// Settle a priced sell: move tokens and SOL, then record it on the curve
+pub(crate) fn execute_sell(
+    ctx: &mut Context<Sell>,
+    amount: u64,
+    sol_output: u64,
+    current_time: i64,
+    guard: PriceGuard,
+) -> Result<()> {
+    guard.check(sol_output, amount, false)?;
 
-pub fn sell(ctx: Context<Sell>, amount: u64, min_sol_output: u64) -> Result<()> {
-    // 1. Access key accounts & validate
     let curve = &mut ctx.accounts.bonding_curve;
     let user = &ctx.accounts.user;
     let global = &ctx.accounts.global;
-    let current_time = Clock::get()?.unix_timestamp;
-    
-    // Check and potentially update curve state based on time
-    curve.check_and_update_state(current_time)?;
-    
-    require!(!curve.complete, HypeBondError::BondingCurveComplete);
-    require!(
-        amount >= curve.min_trade_amount && amount <= curve.max_trade_amount,
-        HypeBondError::InvalidTradeSize
-    );
+    let mint_key = ctx.accounts.mint.key();
 
-    // 2. Calculate SOL output using appropriate formula based on phase
-    let sol_output = curve.calculate_sell_price(amount, current_time)?;
-    require!(sol_output >= min_sol_output, HypeBondError::TooLittleSolReceived);
+    let position = &mut ctx.accounts.user_position;
+    position.bonding_curve = curve.key();
+    position.user = user.key();
+    position.record_sell(amount, sol_output)?;
 
     // 3. Calculate fee (from SOL output)
-    let fee_amount = sol_output
-        .checked_mul(global.fee_basis_points)
-        .unwrap()
-        .checked_div(10000)
-        .unwrap();
+    let fee_amount = math::apply_bps(sol_output, global.fee_basis_points, Rounding::Up)?;
 
     // 4. Transfer tokens: User -> Bonding Curve
     token::transfer(
@@ -45,49 +100,64 @@ pub fn sell(ctx: Context<Sell>, amount: u64, min_sol_output: u64) -> Result<()>
         amount
     )?;
 
-    // 5. Transfer SOL minus fee: Bonding Curve -> User
-    let user_sol_amount = sol_output.checked_sub(fee_amount).unwrap();
-    **ctx.accounts.associated_bonding_curve.to_account_info().try_borrow_mut_lamports()? -= user_sol_amount;
-    **ctx.accounts.user.to_account_info().try_borrow_mut_lamports()? += user_sol_amount;
+    // 5. Transfer SOL minus fee: SOL Vault -> User
+    let user_sol_amount = sol_output
+        .checked_sub(fee_amount)
+        .ok_or(HypeBondError::MathOverflow)?;
+    SolVault::withdraw(
+        &ctx.accounts.sol_vault.to_account_info(),
+        &user.to_account_info(),
+        user_sol_amount,
+    )?;
 
-    // 6. Transfer fee: Bonding Curve -> Fee Recipient 
-    **ctx.accounts.associated_bonding_curve.to_account_info().try_borrow_mut_lamports()? -= fee_amount;
-    **ctx.accounts.fee_recipient.to_account_info().try_borrow_mut_lamports()? += fee_amount;
+    // 6. Transfer fee: SOL Vault -> Fee Recipient 
+    SolVault::withdraw(
+        &ctx.accounts.sol_vault.to_account_info(),
+        &ctx.accounts.fee_recipient.to_account_info(),
+        fee_amount,
+    )?;
 
     // 7. Update bonding curve state with sell impact
     curve.update_reserves(amount, sol_output, false)?;
-    curve.update_volume(amount, current_time);
-
-    // 8. Log phase and price information
-    if curve.curve_state == CurveState::PriceDiscovery {
-        msg!("Sell in discovery phase: {} tokens at {} lamports per token", 
-            amount, 
-            curve.get_discovery_price(current_time)?);
-    } else {
-        msg!("Sell in bonding curve phase: {} tokens for {} SOL", 
-            amount, 
-            sol_output as f64 / 1_000_000_000.0);
-        
-        // Calculate price impact (optional)
-        let virtual_token_ratio = curve.virtual_token_reserves
-            .checked_div(curve.token_total_supply)
-            .unwrap_or(0);
-        
-        msg!("Current token reserves ratio: {}/{}",
-            curve.virtual_token_reserves,
-            curve.token_total_supply);
-    }
+    curve.update_volume(amount, current_time, false);
+    SolVault::check_reserves(&ctx.accounts.sol_vault.to_account_info(), curve.real_sol_reserves)?;
+
+    // 8. Emit the trade with post-trade reserves
+    emit_cpi!(TradeEvent {
+        mint: mint_key,
+        bonding_curve: curve.key(),
+        user: user.key(),
+        is_buy: false,
+        phase: LaunchPhase::from(&curve.curve_state),
+        token_amount: amount,
+        sol_amount: sol_output,
+        fee_amount,
+        price: math::price_of(sol_output, amount)?,
+        virtual_token_reserves: curve.virtual_token_reserves,
+        virtual_sol_reserves: curve.virtual_sol_reserves,
+        real_token_reserves: curve.real_token_reserves,
+        real_sol_reserves: curve.real_sol_reserves,
+        timestamp: current_time,
+    });
 
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Sell<'info> {
     // Global config PDA storing fee and initial parameters
+    #[account(
+        seeds = [b"global"],
+        bump
+    )]
     pub global: Account<'info, Global>,
     
-    // Account that receives trading fees
-    #[account(mut)]
+    /// CHECK: Account that receives trading fees, validated against global
+    #[account(
+        mut,
+        address = global.fee_recipient
+    )]
     pub fee_recipient: UncheckedAccount<'info>,
     
     // Token mint for the trading pair
@@ -109,6 +179,14 @@ pub struct Sell<'info> {
     )]
     pub associated_bonding_curve: Account<'info, TokenAccount>,
     
+    // Program-owned account holding the curve's SOL reserves
+    #[account(
+        mut,
+        seeds = [b"sol-vault", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+    
     // User's token account to receive/send tokens
     #[account(
         mut, 
@@ -121,6 +199,16 @@ pub struct Sell<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
     
+    // Per-wallet position, created on the user's first trade
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + std::mem::size_of::<UserPosition>(),
+        seeds = [b"position", bonding_curve.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub user_position: Account<'info, UserPosition>,
+    
     // Required programs
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
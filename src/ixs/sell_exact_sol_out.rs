@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use crate::{errors::HypeBondError, guards::Deadline};
+use super::sell::{Sell, begin_sell, execute_sell};
+
+pub fn sell_exact_sol_out(
+    mut ctx: Context<Sell>,
+    sol_amount: u64,
+    max_tokens_in: u64,
+    deadline: Option<Deadline>,
+    max_price_impact_bps: Option<u64>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let guard = begin_sell(&mut ctx, &clock, deadline, max_price_impact_bps)?;
+
+    // 1. Find the fewest tokens that pay out sol_amount after fee and penalties
+    let curve = &ctx.accounts.bonding_curve;
+    let global = &ctx.accounts.global;
+    let policy = curve.effective_policy(&global.protection_policy);
+    let amount = curve.sell_amount_for_sol(sol_amount, global.fee_basis_points, current_time, &policy)?;
+    require!(amount <= max_tokens_in, HypeBondError::SlippageExceeded);
+
+    // 2. The user receives at least sol_amount; rounding can add a few lamports
+    let sol_output = curve.calculate_sell_price(amount, current_time, &policy)?;
+
+    execute_sell(&mut ctx, amount, sol_output, current_time, guard)
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::{
+    curve::BondingCurve,
+    errors::HypeBondError,
+    contexts::{Global, ProtectionPolicy},
+    events::ProtectionPolicyUpdatedEvent,
+};
+
+// Override the global policy for a single launch, or clear the override with None
+pub fn set_curve_protection_policy(
+    ctx: Context<SetCurveProtectionPolicy>,
+    policy: Option<ProtectionPolicy>,
+) -> Result<()> {
+    if let Some(policy) = &policy {
+        require!(policy.is_valid(), HypeBondError::InvalidProtectionPolicy);
+    }
+
+    ctx.accounts.bonding_curve.protection_override = policy;
+
+    emit_cpi!(ProtectionPolicyUpdatedEvent {
+        authority: ctx.accounts.authority.key(),
+        bonding_curve: Some(ctx.accounts.bonding_curve.key()),
+        policy,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetCurveProtectionPolicy<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump,
+        constraint = global.initialized @ HypeBondError::NotInitialized,
+        constraint = global.authority == authority.key() @ HypeBondError::NotAuthorized
+    )]
+    pub global: Account<'info, Global>,
+
+    pub authority: Signer<'info>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"bonding-curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+}
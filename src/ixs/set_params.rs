@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
-use crate::{curve::BondingCurve, errors::HypeBondError, contexts::Global};
+use crate::{curve::{BondingCurve, CurveState}, errors::HypeBondError, contexts::Global, events::ParamsUpdatedEvent};
 
 pub fn set_params(
     ctx: Context<SetParams>,
@@ -13,6 +13,7 @@ pub fn set_params(
     discovery_duration: i64,        // Duration of discovery phase in seconds
     discovery_start_price: u64,     // Starting price in lamports per token
     discovery_end_price: u64,       // Ending price in lamports per token
+    discovery_start_time: Option<i64>,  // Scheduled start, defaults to now
 ) -> Result<()> {
     require!(ctx.accounts.global.initialized, HypeBondError::NotInitialized);
     require!(
@@ -34,22 +35,50 @@ pub fn set_params(
     global.base_slippage = 100; // 1% base slippage
     global.volume_multiplier = 10; // 0.1% additional slippage per volume threshold
 
-    // Initialize bonding curve with discovery parameters
+    // Reschedule a curve's discovery, only before it has opened; supply and
+    // reserves were fixed by create_bonding_curve
+    let current_time = Clock::get()?.unix_timestamp;
+    let mut curve_key = None;
+    let mut scheduled_start = discovery_start_time.unwrap_or(0);
     if let Some(bonding_curve) = &mut ctx.accounts.bonding_curve {
-        let current_time = Clock::get()?.unix_timestamp;
-        bonding_curve.initialize_discovery(
-            current_time,
+        require!(
+            bonding_curve.curve_state == CurveState::PriceDiscovery
+                && current_time < bonding_curve.discovery_start_time,
+            HypeBondError::DiscoveryAlreadyStarted
+        );
+
+        scheduled_start = BondingCurve::scheduled_start(discovery_start_time, current_time)?;
+        let decay_mode = bonding_curve.decay_mode;
+        bonding_curve.schedule_discovery(
+            scheduled_start,
             discovery_duration,
             discovery_start_price,
             discovery_end_price,
-            token_total_supply,
+            decay_mode,
         )?;
-        msg!("Initialized discovery phase for bonding curve");
+        curve_key = Some(bonding_curve.key());
     }
 
+    emit_cpi!(ParamsUpdatedEvent {
+        authority: ctx.accounts.user.key(),
+        fee_recipient,
+        initial_virtual_token_reserves,
+        initial_virtual_sol_reserves,
+        initial_real_token_reserves,
+        token_total_supply,
+        fee_basis_points,
+        bonding_curve: curve_key,
+        discovery_start_time: scheduled_start,
+        discovery_duration,
+        discovery_start_price,
+        discovery_end_price,
+        timestamp: current_time,
+    });
+
     Ok(())
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SetParams<'info> {
     #[account(
@@ -62,7 +91,7 @@ pub struct SetParams<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
-    // Optional bonding curve account
+    // Optional bonding curve to reschedule before its discovery opens
     #[account(
         mut,
         seeds = [b"bonding-curve", mint.key().as_ref()],
@@ -74,10 +103,4 @@ pub struct SetParams<'info> {
     pub mint: Account<'info, Mint>,
 
     pub system_program: Program<'info, System>,
-
-    /// CHECK: Event authority
-    pub event_authority: UncheckedAccount<'info>,
-
-    /// CHECK: Program
-    pub program: UncheckedAccount<'info>,
 }
\ No newline at end of file
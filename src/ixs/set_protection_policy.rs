@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::{errors::HypeBondError, contexts::{Global, ProtectionPolicy}, events::ProtectionPolicyUpdatedEvent};
+
+pub fn set_protection_policy(
+    ctx: Context<SetProtectionPolicy>,
+    policy: ProtectionPolicy,
+) -> Result<()> {
+    require!(policy.is_valid(), HypeBondError::InvalidProtectionPolicy);
+
+    ctx.accounts.global.protection_policy = policy;
+
+    emit_cpi!(ProtectionPolicyUpdatedEvent {
+        authority: ctx.accounts.authority.key(),
+        bonding_curve: None,
+        policy: Some(policy),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetProtectionPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [b"global"],
+        bump,
+        constraint = global.initialized @ HypeBondError::NotInitialized,
+        constraint = global.authority == authority.key() @ HypeBondError::NotAuthorized
+    )]
+    pub global: Account<'info, Global>,
+
+    pub authority: Signer<'info>,
+}
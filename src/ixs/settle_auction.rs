@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::{
+    auction::BidBook,
+    curve::{BondingCurve, CurveState, DiscoveryMode},
+    errors::HypeBondError,
+    events::{LaunchPhase, PhaseTransitionEvent},
+};
+
+pub fn settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
+    let curve = &mut ctx.accounts.bonding_curve;
+    let bid_book = &mut ctx.accounts.bid_book;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // 1. Settlement opens once the bidding window closes
+    require!(
+        curve.discovery_mode == DiscoveryMode::BatchAuction
+            && curve.curve_state == CurveState::PriceDiscovery,
+        HypeBondError::WrongDiscoveryMode
+    );
+    require!(
+        current_time >= curve.discovery_end_time(),
+        HypeBondError::DiscoveryNotEnded
+    );
+
+    // 2. Compute the uniform clearing price and pro rata fill
+    bid_book.bonding_curve = curve.key();
+    let supply = curve.auction_token_supply.min(curve.real_token_reserves);
+    bid_book.settle(curve, supply)?;
+
+    // 3. Fail the launch if the auction missed its reserve; bids get refunded
+    if curve.misses_reserve(bid_book.clearing_price, bid_book.filled_cost) {
+        bid_book.cancel_fills();
+        curve.curve_state = CurveState::Failed;
+    } else {
+        // 4. Transition to the bonding curve at the clearing price
+        curve.complete_auction(bid_book.clearing_price, bid_book.reserved_tokens, bid_book.filled_cost)?;
+    }
+
+    emit_cpi!(PhaseTransitionEvent::new(
+        ctx.accounts.mint.key(),
+        curve,
+        LaunchPhase::PriceDiscovery,
+        LaunchPhase::from(&curve.curve_state),
+        current_time,
+    ));
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SettleAuction<'info> {
+    // Anyone can settle once the window has closed
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"bonding-curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    // Created here if the auction closed without any bids
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + std::mem::size_of::<BidBook>(),
+        seeds = [b"bid-book", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub bid_book: Account<'info, BidBook>,
+
+    pub system_program: Program<'info, System>,
+}
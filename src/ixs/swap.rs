@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::{self, Token, TokenAccount, Mint},
+    associated_token::AssociatedToken,
+};
+use crate::{
+    pool::{Pool, SwapDirection},
+    errors::HypeBondError,
+    contexts::SolVault,
+    events::PoolSwapEvent,
+    math,
+};
+
+pub fn swap(
+    ctx: Context<Swap>,
+    amount_in: u64,
+    min_amount_out: u64,
+    direction: SwapDirection,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let user = &ctx.accounts.user;
+
+    // 1. Calculate output from the constant product formula
+    let amount_out = pool.get_swap_output(amount_in, direction)?;
+    require!(amount_out >= min_amount_out, HypeBondError::SlippageExceeded);
+
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[
+        b"pool".as_ref(),
+        mint_key.as_ref(),
+        &[ctx.bumps.pool]
+    ];
+    let signer = &[&seeds[..]];
+
+    // 2. Move funds in and out of the pool
+    match direction {
+        SwapDirection::SolToToken => {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: user.to_account_info(),
+                        to: ctx.accounts.pool_sol_vault.to_account_info(),
+                    },
+                ),
+                amount_in
+            )?;
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: ctx.accounts.pool_token_account.to_account_info(),
+                        to: ctx.accounts.associated_user.to_account_info(),
+                        authority: pool.to_account_info(),
+                    },
+                    signer
+                ),
+                amount_out
+            )?;
+        },
+        SwapDirection::TokenToSol => {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: ctx.accounts.associated_user.to_account_info(),
+                        to: ctx.accounts.pool_token_account.to_account_info(),
+                        authority: user.to_account_info(),
+                    },
+                ),
+                amount_in
+            )?;
+
+            SolVault::withdraw(
+                &ctx.accounts.pool_sol_vault.to_account_info(),
+                &user.to_account_info(),
+                amount_out,
+            )?;
+        }
+    }
+
+    // 3. Update pool reserves
+    pool.apply_swap(amount_in, amount_out, direction)?;
+    SolVault::check_reserves(&ctx.accounts.pool_sol_vault.to_account_info(), pool.sol_reserves)?;
+
+    let (token_amount, sol_amount) = match direction {
+        SwapDirection::SolToToken => (amount_out, amount_in),
+        SwapDirection::TokenToSol => (amount_in, amount_out),
+    };
+    emit_cpi!(PoolSwapEvent {
+        mint: mint_key,
+        pool: pool.key(),
+        user: user.key(),
+        is_buy: direction == SwapDirection::SolToToken,
+        amount_in,
+        amount_out,
+        fee_amount: pool.swap_fee(amount_in)?,
+        price: math::price_of(sol_amount, token_amount)?,
+        token_reserves: pool.token_reserves,
+        sol_reserves: pool.sol_reserves,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"pool", mint.key().as_ref()],
+        bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    // Token account owned by the pool (holds tokens)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = pool
+    )]
+    pub pool_token_account: Account<'info, TokenAccount>,
+
+    // Program-owned account holding the pool's SOL reserves
+    #[account(
+        mut,
+        seeds = [b"sol-vault", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_sol_vault: Account<'info, SolVault>,
+
+    // User's token account to receive/send tokens
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = user
+    )]
+    pub associated_user: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::{contexts::*, errors::HypeBondError, events::VoteWithdrawnEvent};
+
+pub fn withdraw_vote(ctx: Context<WithdrawVote>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let amount = ctx.accounts.vote_record.amount;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // 1. Deposits stay locked so the same tokens can't vote twice
+    require!(
+        proposal.has_ended(current_time),
+        HypeBondError::VotingNotEnded
+    );
+
+    // 2. Return voting tokens: Proposal Vault -> Voter
+    let proposal_id = [proposal.proposal_id];
+    let seeds = &[
+        b"proposal".as_ref(),
+        proposal.milestone.as_ref(),
+        &proposal_id,
+        &[ctx.bumps.proposal]
+    ];
+    let signer = &[&seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.proposal_vault.to_account_info(),
+                to: ctx.accounts.voter_token_account.to_account_info(),
+                authority: proposal.to_account_info(),
+            },
+            signer
+        ),
+        amount
+    )?;
+
+    emit_cpi!(VoteWithdrawnEvent {
+        proposal: proposal.key(),
+        voter: ctx.accounts.voter.key(),
+        amount,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [b"proposal", proposal.milestone.as_ref(), &[proposal.proposal_id]],
+        bump
+    )]
+    pub proposal: Account<'info, MilestoneProposal>,
+
+    #[account(
+        mut,
+        close = voter,
+        seeds = [b"vote", proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal-vault", proposal.key().as_ref()],
+        bump
+    )]
+    pub proposal_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = proposal_vault.mint,
+        token::authority = voter
+    )]
+    pub voter_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -1,7 +1,15 @@
+#![allow(clippy::too_many_arguments)]
+
 use anchor_lang::prelude::*;
 
 mod errors;
+mod events;
+mod guards;
+mod allowlist;
 mod curve;
+mod math;
+mod pool;
+mod auction;
 mod constants;
 mod ixs;
 mod contexts;
@@ -10,6 +18,9 @@ mod contexts;
 // The problem is these imports don't propagate into the program module
 use ixs::*;
 use contexts::*;
+use pool::SwapDirection;
+use guards::Deadline;
+use allowlist::AllowlistProof;
 
 declare_id!("BiGyz1fq35QxV357XKBUxVHXaHim9MnEk51J9aRB9FBZ");
 
@@ -32,6 +43,7 @@ pub mod hype_bond {
         discovery_duration: i64,
         discovery_start_price: u64,
         discovery_end_price: u64,
+        discovery_start_time: Option<i64>,
     ) -> Result<()> {
         ixs::set_params(
             ctx,
@@ -44,9 +56,24 @@ pub mod hype_bond {
             discovery_duration,
             discovery_start_price,
             discovery_end_price,
+            discovery_start_time,
         )
     }
     
+    pub fn set_protection_policy(
+        ctx: Context<SetProtectionPolicy>,
+        policy: ProtectionPolicy,
+    ) -> Result<()> {
+        ixs::set_protection_policy(ctx, policy)
+    }
+    
+    pub fn set_curve_protection_policy(
+        ctx: Context<SetCurveProtectionPolicy>,
+        policy: Option<ProtectionPolicy>,
+    ) -> Result<()> {
+        ixs::set_curve_protection_policy(ctx, policy)
+    }
+    
     pub fn create_project(
         ctx: Context<CreateProject>,
         name: String,
@@ -56,6 +83,8 @@ pub mod hype_bond {
         team_percentage: u8,
         treasury_percentage: u8,
         team_allocation: TeamAllocation,
+        team_vesting: VestingConfig,
+        governance: GovernanceConfig,
     ) -> Result<()> {
         ixs::create_project(
             ctx,
@@ -66,27 +95,165 @@ pub mod hype_bond {
             team_percentage,
             treasury_percentage,
             team_allocation,
+            team_vesting,
+            governance,
         )
     }
     
+    pub fn create_bonding_curve(
+        ctx: Context<CreateBondingCurve>,
+        params: BondingCurveParams,
+    ) -> Result<()> {
+        ixs::create_bonding_curve(ctx, params)
+    }
+    
     pub fn create_milestone(
         ctx: Context<CreateMilestone>,
         milestone_id: u8,
         description: String,
         requirements: Vec<String>,
+        deadline: i64,
     ) -> Result<()> {
-        ixs::create_milestone(ctx, milestone_id, description, requirements)
+        ixs::create_milestone(ctx, milestone_id, description, requirements, deadline)
     }
     
+    pub fn propose_milestone(ctx: Context<ProposeMilestone>, milestone_id: u8) -> Result<()> {
+        ixs::propose_milestone(ctx, milestone_id)
+    }
+
+    pub fn cast_vote(ctx: Context<CastVote>, approve: bool, amount: u64) -> Result<()> {
+        ixs::cast_vote(ctx, approve, amount)
+    }
+
+    pub fn withdraw_vote(ctx: Context<WithdrawVote>) -> Result<()> {
+        ixs::withdraw_vote(ctx)
+    }
+
     pub fn complete_milestone(ctx: Context<CompleteMilestone>, milestone_id: u8) -> Result<()> {
         ixs::complete_milestone(ctx, milestone_id)
     }
+
+    pub fn abandon_project(ctx: Context<AbandonProject>, milestone_id: u8) -> Result<()> {
+        ixs::abandon_project(ctx, milestone_id)
+    }
+
+    pub fn redeem(ctx: Context<Redeem>, amount: u64) -> Result<()> {
+        ixs::redeem(ctx, amount)
+    }
+
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        ixs::claim_vested(ctx)
+    }
+
+    pub fn claim_team_allocation(ctx: Context<ClaimTeamAllocation>, milestone_id: u8) -> Result<()> {
+        ixs::claim_team_allocation(ctx, milestone_id)
+    }
+
+    pub fn release_escrow(ctx: Context<ReleaseEscrow>, milestone_id: u8) -> Result<()> {
+        ixs::release_escrow(ctx, milestone_id)
+    }
     
-    pub fn buy(ctx: Context<Buy>, amount: u64, max_sol_cost: u64) -> Result<()> {
-        ixs::buy(ctx, amount, max_sol_cost)
+    pub fn buy(
+        ctx: Context<Buy>,
+        amount: u64,
+        max_sol_cost: u64,
+        deadline: Option<Deadline>,
+        max_price_impact_bps: Option<u64>,
+        allowlist_proof: Option<AllowlistProof>,
+    ) -> Result<()> {
+        ixs::buy(ctx, amount, max_sol_cost, deadline, max_price_impact_bps, allowlist_proof)
     }
     
-    pub fn sell(ctx: Context<Sell>, amount: u64, min_sol_output: u64) -> Result<()> {
-        ixs::sell(ctx, amount, min_sol_output)
+    pub fn sell(
+        ctx: Context<Sell>,
+        amount: u64,
+        min_sol_output: u64,
+        deadline: Option<Deadline>,
+        max_price_impact_bps: Option<u64>,
+    ) -> Result<()> {
+        ixs::sell(ctx, amount, min_sol_output, deadline, max_price_impact_bps)
+    }
+
+    pub fn buy_exact_sol_in(
+        ctx: Context<Buy>,
+        sol_amount: u64,
+        min_tokens_out: u64,
+        deadline: Option<Deadline>,
+        max_price_impact_bps: Option<u64>,
+        allowlist_proof: Option<AllowlistProof>,
+    ) -> Result<()> {
+        ixs::buy_exact_sol_in(
+            ctx,
+            sol_amount,
+            min_tokens_out,
+            deadline,
+            max_price_impact_bps,
+            allowlist_proof,
+        )
+    }
+
+    pub fn sell_exact_sol_out(
+        ctx: Context<Sell>,
+        sol_amount: u64,
+        max_tokens_in: u64,
+        deadline: Option<Deadline>,
+        max_price_impact_bps: Option<u64>,
+    ) -> Result<()> {
+        ixs::sell_exact_sol_out(ctx, sol_amount, max_tokens_in, deadline, max_price_impact_bps)
+    }
+
+    pub fn quote_buy(ctx: Context<GetQuote>, amount: u64) -> Result<TradeQuote> {
+        ixs::quote_buy(ctx, amount)
+    }
+
+    pub fn quote_sell(ctx: Context<GetQuote>, amount: u64) -> Result<TradeQuote> {
+        ixs::quote_sell(ctx, amount)
+    }
+    
+    pub fn place_bid(ctx: Context<PlaceBid>, level: u8, sol_amount: u64) -> Result<()> {
+        ixs::place_bid(ctx, level, sol_amount)
+    }
+    
+    pub fn settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
+        ixs::settle_auction(ctx)
+    }
+    
+    pub fn claim_bid(ctx: Context<ClaimBid>, level: u8) -> Result<()> {
+        ixs::claim_bid(ctx, level)
+    }
+    
+    pub fn claim_refund(ctx: Context<ClaimRefund>, amount: u64) -> Result<()> {
+        ixs::claim_refund(ctx, amount)
+    }
+    
+    pub fn graduate(ctx: Context<Graduate>) -> Result<()> {
+        ixs::graduate(ctx)
+    }
+    
+    pub fn swap(
+        ctx: Context<Swap>,
+        amount_in: u64,
+        min_amount_out: u64,
+        direction: SwapDirection,
+    ) -> Result<()> {
+        ixs::swap(ctx, amount_in, min_amount_out, direction)
+    }
+    
+    pub fn add_liquidity(
+        ctx: Context<AddLiquidity>,
+        lp_amount: u64,
+        max_token_amount: u64,
+        max_sol_amount: u64,
+    ) -> Result<()> {
+        ixs::add_liquidity(ctx, lp_amount, max_token_amount, max_sol_amount)
+    }
+    
+    pub fn remove_liquidity(
+        ctx: Context<RemoveLiquidity>,
+        lp_amount: u64,
+        min_token_amount: u64,
+        min_sol_amount: u64,
+    ) -> Result<()> {
+        ixs::remove_liquidity(ctx, lp_amount, min_token_amount, min_sol_amount)
     }
 }
\ No newline at end of file
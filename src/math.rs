@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use crate::constants::PRICE_PRECISION;
+use crate::errors::HypeBondError;
+
+// Fixed-point helpers shared by the curve, pool and auction pricing.
+// All intermediates are u128 and every overflow is an error; callers pick
+// the rounding direction so results always favor the pool over the trader.
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    Down,
+    Up
+}
+
+pub fn to_u64(value: u128) -> Result<u64> {
+    u64::try_from(value).map_err(|_| HypeBondError::MathOverflow.into())
+}
+
+// a * b / c in u128
+pub fn mul_div_u128(a: u128, b: u128, c: u128, rounding: Rounding) -> Result<u128> {
+    require!(c > 0, HypeBondError::MathOverflow);
+    let product = a.checked_mul(b).ok_or(HypeBondError::MathOverflow)?;
+    Ok(match rounding {
+        Rounding::Down => product / c,
+        Rounding::Up => product.div_ceil(c),
+    })
+}
+
+// a * b / c, narrowed back to u64
+pub fn mul_div(a: u64, b: u64, c: u64, rounding: Rounding) -> Result<u64> {
+    to_u64(mul_div_u128(a as u128, b as u128, c as u128, rounding)?)
+}
+
+// Scale an amount by a basis point factor (10000 = 1x)
+pub fn apply_bps(amount: u64, bps: u64, rounding: Rounding) -> Result<u64> {
+    mul_div(amount, bps, 10000, rounding)
+}
+
+// Lamports for token_amount at a price quoted per PRICE_PRECISION tokens
+pub fn tokens_to_sol(token_amount: u64, price: u64, rounding: Rounding) -> Result<u64> {
+    mul_div(token_amount, price, PRICE_PRECISION, rounding)
+}
+
+// Average price paid, in lamports per PRICE_PRECISION tokens
+pub fn price_of(sol_amount: u64, token_amount: u64) -> Result<u64> {
+    mul_div(sol_amount, PRICE_PRECISION, token_amount, Rounding::Down)
+}
+
+// Adverse move of an execution price from a reference price, in basis points
+pub fn price_impact_bps(reference_price: u64, execution_price: u64, is_buy: bool) -> Result<u64> {
+    if reference_price == 0 {
+        return Ok(0);
+    }
+    let adverse_move = if is_buy {
+        execution_price.saturating_sub(reference_price)
+    } else {
+        reference_price.saturating_sub(execution_price)
+    };
+    mul_div(adverse_move, 10000, reference_price, Rounding::Up)
+}
+
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+pub fn isqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+    let mut x = value;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overflow() -> Error {
+        HypeBondError::MathOverflow.into()
+    }
+
+    #[test]
+    fn mul_div_rounds_in_requested_direction() {
+        assert_eq!(mul_div(10, 1, 3, Rounding::Down).unwrap(), 3);
+        assert_eq!(mul_div(10, 1, 3, Rounding::Up).unwrap(), 4);
+        assert_eq!(mul_div(9, 1, 3, Rounding::Down).unwrap(), 3);
+        assert_eq!(mul_div(9, 1, 3, Rounding::Up).unwrap(), 3);
+        assert_eq!(mul_div(0, 7, 3, Rounding::Up).unwrap(), 0);
+    }
+
+    #[test]
+    fn mul_div_keeps_u128_intermediates() {
+        assert_eq!(mul_div(u64::MAX, u64::MAX, u64::MAX, Rounding::Down).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn mul_div_reports_overflow() {
+        assert_eq!(mul_div(1, 1, 0, Rounding::Down).unwrap_err(), overflow());
+        assert_eq!(mul_div(u64::MAX, 2, 1, Rounding::Down).unwrap_err(), overflow());
+        assert_eq!(mul_div_u128(u128::MAX, 2, 1, Rounding::Down).unwrap_err(), overflow());
+        assert_eq!(to_u64(u64::MAX as u128 + 1).unwrap_err(), overflow());
+    }
+
+    #[test]
+    fn apply_bps_rounds_in_requested_direction() {
+        assert_eq!(apply_bps(1, 5000, Rounding::Down).unwrap(), 0);
+        assert_eq!(apply_bps(1, 5000, Rounding::Up).unwrap(), 1);
+        assert_eq!(apply_bps(10_000, 25, Rounding::Down).unwrap(), 25);
+    }
+
+    #[test]
+    fn tokens_to_sol_and_price_of_are_inverse() {
+        let price = 30_000;     // 30k lamports per PRICE_PRECISION tokens
+        let sol = tokens_to_sol(5 * PRICE_PRECISION, price, Rounding::Down).unwrap();
+        assert_eq!(sol, 150_000);
+        assert_eq!(price_of(sol, 5 * PRICE_PRECISION).unwrap(), price);
+
+        // Sub-lamport costs round up for buyers and down for sellers
+        assert_eq!(tokens_to_sol(1, price, Rounding::Up).unwrap(), 1);
+        assert_eq!(tokens_to_sol(1, price, Rounding::Down).unwrap(), 0);
+    }
+
+    #[test]
+    fn price_impact_counts_only_adverse_moves() {
+        assert_eq!(price_impact_bps(3, 4, true).unwrap(), 3334);    // 3333.3 bps rounds up
+        assert_eq!(price_impact_bps(10_000, 9_000, true).unwrap(), 0);
+        assert_eq!(price_impact_bps(10_000, 9_000, false).unwrap(), 1000);
+        assert_eq!(price_impact_bps(0, 9_000, false).unwrap(), 0);
+    }
+
+    #[test]
+    fn isqrt_floors() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(1), 1);
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+        assert_eq!(isqrt(u64::MAX as u128 * u64::MAX as u128), u64::MAX as u128);
+    }
+
+    #[test]
+    fn gcd_matches_known_values() {
+        assert_eq!(gcd(PRICE_PRECISION, 30_000), 10_000);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(gcd(17, 5), 1);
+    }
+}
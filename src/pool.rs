@@ -0,0 +1,195 @@
+use anchor_lang::prelude::*;
+use crate::errors::HypeBondError;
+use crate::math::{self, Rounding, mul_div};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SwapDirection {
+    SolToToken,
+    TokenToSol
+}
+
+// Constant product (x*y=k) pool that takes over trading once a curve graduates
+#[account]
+#[derive(Default)]
+pub struct Pool {
+    pub mint: Pubkey,
+    pub lp_mint: Pubkey,
+    pub token_reserves: u64,
+    pub sol_reserves: u64,
+    pub lp_supply: u64,         // Total LP outstanding, including locked liquidity
+    pub locked_lp: u64,         // LP backing the migrated curve liquidity, never minted
+    pub fee_basis_points: u64,  // Swap fee left in the pool for LPs
+}
+
+impl Pool {
+    // Seed the pool with the liquidity migrated from a graduated curve
+    pub fn initialize(
+        &mut self,
+        mint: Pubkey,
+        lp_mint: Pubkey,
+        token_amount: u64,
+        sol_amount: u64,
+        fee_basis_points: u64,
+    ) -> Result<()> {
+        require!(token_amount > 0 && sol_amount > 0, HypeBondError::InsufficientLiquidity);
+
+        let initial_lp = math::to_u64(math::isqrt((token_amount as u128) * (sol_amount as u128)))?;
+
+        self.mint = mint;
+        self.lp_mint = lp_mint;
+        self.token_reserves = token_amount;
+        self.sol_reserves = sol_amount;
+        self.lp_supply = initial_lp;
+        self.locked_lp = initial_lp;
+        self.fee_basis_points = fee_basis_points;
+
+        Ok(())
+    }
+
+    fn reserves_for(&self, direction: SwapDirection) -> (u64, u64) {
+        match direction {
+            SwapDirection::SolToToken => (self.sol_reserves, self.token_reserves),
+            SwapDirection::TokenToSol => (self.token_reserves, self.sol_reserves),
+        }
+    }
+
+    // Fee kept from a swap's input, rounded in the pool's favor
+    pub fn swap_fee(&self, amount_in: u64) -> Result<u64> {
+        let amount_in_after_fee = math::apply_bps(
+            amount_in,
+            10000u64.saturating_sub(self.fee_basis_points),
+            Rounding::Down,
+        )?;
+        Ok(amount_in - amount_in_after_fee)
+    }
+
+    // Output amount for a swap, with the fee taken from the input
+    pub fn get_swap_output(&self, amount_in: u64, direction: SwapDirection) -> Result<u64> {
+        let (reserve_in, reserve_out) = self.reserves_for(direction);
+
+        let amount_in_after_fee = amount_in - self.swap_fee(amount_in)?;
+        let new_reserve_in = reserve_in
+            .checked_add(amount_in_after_fee)
+            .ok_or(HypeBondError::MathOverflow)?;
+
+        let amount_out = mul_div(amount_in_after_fee, reserve_out, new_reserve_in, Rounding::Down)?;
+        require!(amount_out > 0 && amount_out < reserve_out, HypeBondError::InsufficientLiquidity);
+
+        Ok(amount_out)
+    }
+
+    // Update reserves after a swap; the full input (fee included) stays in the pool
+    pub fn apply_swap(&mut self, amount_in: u64, amount_out: u64, direction: SwapDirection) -> Result<()> {
+        let (reserve_in, reserve_out) = match direction {
+            SwapDirection::SolToToken => (&mut self.sol_reserves, &mut self.token_reserves),
+            SwapDirection::TokenToSol => (&mut self.token_reserves, &mut self.sol_reserves),
+        };
+
+        *reserve_in = reserve_in
+            .checked_add(amount_in)
+            .ok_or(HypeBondError::MathOverflow)?;
+        *reserve_out = reserve_out
+            .checked_sub(amount_out)
+            .ok_or(HypeBondError::InsufficientLiquidity)?;
+
+        Ok(())
+    }
+
+    // Token and SOL a depositor must add to mint lp_amount (rounded up)
+    pub fn calculate_deposit(&self, lp_amount: u64) -> Result<(u64, u64)> {
+        require!(lp_amount > 0, HypeBondError::InsufficientLiquidity);
+        let token_amount = mul_div(lp_amount, self.token_reserves, self.lp_supply, Rounding::Up)?;
+        let sol_amount = mul_div(lp_amount, self.sol_reserves, self.lp_supply, Rounding::Up)?;
+        Ok((token_amount, sol_amount))
+    }
+
+    // Token and SOL returned for burning lp_amount (rounded down)
+    pub fn calculate_withdrawal(&self, lp_amount: u64) -> Result<(u64, u64)> {
+        require!(
+            lp_amount > 0 && lp_amount <= self.lp_supply.saturating_sub(self.locked_lp),
+            HypeBondError::InsufficientLiquidity
+        );
+        let token_amount = mul_div(lp_amount, self.token_reserves, self.lp_supply, Rounding::Down)?;
+        let sol_amount = mul_div(lp_amount, self.sol_reserves, self.lp_supply, Rounding::Down)?;
+        Ok((token_amount, sol_amount))
+    }
+
+    pub fn deposit(&mut self, lp_amount: u64, token_amount: u64, sol_amount: u64) -> Result<()> {
+        self.lp_supply = self.lp_supply
+            .checked_add(lp_amount)
+            .ok_or(HypeBondError::MathOverflow)?;
+        self.token_reserves = self.token_reserves
+            .checked_add(token_amount)
+            .ok_or(HypeBondError::MathOverflow)?;
+        self.sol_reserves = self.sol_reserves
+            .checked_add(sol_amount)
+            .ok_or(HypeBondError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn withdraw(&mut self, lp_amount: u64, token_amount: u64, sol_amount: u64) -> Result<()> {
+        self.lp_supply = self.lp_supply
+            .checked_sub(lp_amount)
+            .ok_or(HypeBondError::MathOverflow)?;
+        self.token_reserves = self.token_reserves
+            .checked_sub(token_amount)
+            .ok_or(HypeBondError::MathOverflow)?;
+        self.sol_reserves = self.sol_reserves
+            .checked_sub(sol_amount)
+            .ok_or(HypeBondError::MathOverflow)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool() -> Pool {
+        let mut pool = Pool::default();
+        pool.initialize(Pubkey::default(), Pubkey::default(), 1_000_000_000, 4_000_000, 100).unwrap();
+        pool
+    }
+
+    #[test]
+    fn initial_liquidity_is_locked() {
+        let pool = pool();
+        assert_eq!(pool.lp_supply, 63_245_553);
+        assert_eq!(pool.locked_lp, pool.lp_supply);
+        assert!(pool.calculate_withdrawal(1).is_err());
+    }
+
+    #[test]
+    fn swaps_round_down_and_grow_k() {
+        for direction in [SwapDirection::SolToToken, SwapDirection::TokenToSol] {
+            let mut pool = pool();
+            let k = pool.token_reserves as u128 * pool.sol_reserves as u128;
+
+            let amount_out = pool.get_swap_output(10_000, direction).unwrap();
+            pool.apply_swap(10_000, amount_out, direction).unwrap();
+            assert!(pool.token_reserves as u128 * pool.sol_reserves as u128 > k);
+        }
+        assert_eq!(pool().swap_fee(10_000).unwrap(), 100);
+        assert_eq!(pool().swap_fee(1).unwrap(), 1);
+    }
+
+    #[test]
+    fn swaps_cannot_drain_a_side() {
+        let pool = pool();
+        assert!(pool.get_swap_output(1, SwapDirection::TokenToSol).is_err());
+        let amount_out = pool.get_swap_output(u64::MAX / 2, SwapDirection::SolToToken).unwrap();
+        assert!(amount_out < pool.token_reserves);
+    }
+
+    #[test]
+    fn deposits_round_up_and_withdrawals_round_down() {
+        let mut pool = pool();
+        let (token_in, sol_in) = pool.calculate_deposit(3).unwrap();
+        assert_eq!((token_in, sol_in), (48, 1));
+        pool.deposit(3, token_in, sol_in).unwrap();
+
+        let (token_out, sol_out) = pool.calculate_withdrawal(3).unwrap();
+        assert!(token_out <= token_in && sol_out <= sol_in);
+        assert_eq!(sol_out, 0);
+    }
+}